@@ -1,3 +1,6 @@
+// The pad and push benchmarks pass the unit result of the padding functions to black_box.
+#![allow(clippy::unit_arg)]
+
 use criterion::criterion_main;
 mod benchmarks;
 
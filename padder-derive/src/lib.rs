@@ -0,0 +1,245 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Derive macro for reading and writing structs as fixed-width records with padder.
+//!
+//! This crate is not meant to be used directly, enable the `derive` feature of padder
+//! instead, which re-exports the [`FixedWidth`](macro@FixedWidth) derive macro.
+//!
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitInt, LitStr};
+
+/// The names of the `padder::Symbol` variants, in lowercase, that can be used as the
+/// `symbol` of a field.
+const SYMBOLS: [(&str, &str); 22] = [
+    ("hyphen", "Hyphen"),
+    ("underscore", "Underscore"),
+    ("dot", "Dot"),
+    ("comma", "Comma"),
+    ("colon", "Colon"),
+    ("semicolon", "Semicolon"),
+    ("hashtag", "Hashtag"),
+    ("backslash", "Backslash"),
+    ("forwardslash", "Forwardslash"),
+    ("asterisk", "Asterisk"),
+    ("whitespace", "Whitespace"),
+    ("zero", "Zero"),
+    ("one", "One"),
+    ("two", "Two"),
+    ("three", "Three"),
+    ("four", "Four"),
+    ("five", "Five"),
+    ("six", "Six"),
+    ("seven", "Seven"),
+    ("eight", "Eight"),
+    ("nine", "Nine"),
+    ("space", "Whitespace"),
+];
+
+/// The padding options of one struct field, parsed from its `#[fixed_width]` attribute.
+struct Options {
+    width: usize,
+    align: Ident,
+    symbol: Ident,
+}
+
+/// Parse the `#[fixed_width(width = .., align = "..", symbol = "..")]` attribute.
+fn parse_options(field: &syn::Field) -> syn::Result<Options> {
+    let mut width: Option<usize> = None;
+    let mut align = Ident::new("Right", Span::call_site());
+    let mut symbol = Ident::new("Whitespace", Span::call_site());
+
+    let attribute = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("fixed_width"))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(field, "missing #[fixed_width(width = ..)] attribute")
+        })?;
+
+    attribute.parse_nested_meta(|meta| {
+        if meta.path.is_ident("width") {
+            let value: LitInt = meta.value()?.parse()?;
+            width = Some(value.base10_parse()?);
+            Ok(())
+        } else if meta.path.is_ident("align") {
+            let value: LitStr = meta.value()?.parse()?;
+            let variant: &str = match value.value().to_ascii_lowercase().as_str() {
+                "left" => "Left",
+                "right" => "Right",
+                "center" => "Center",
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "expected \"left\", \"right\" or \"center\"",
+                    ))
+                }
+            };
+            align = Ident::new(variant, value.span());
+            Ok(())
+        } else if meta.path.is_ident("symbol") {
+            let value: LitStr = meta.value()?.parse()?;
+            let name: String = value.value().to_ascii_lowercase();
+            let (_, variant) = SYMBOLS
+                .iter()
+                .find(|(n, _)| *n == name)
+                .ok_or_else(|| syn::Error::new_spanned(&value, "unknown padding symbol"))?;
+            symbol = Ident::new(variant, value.span());
+            Ok(())
+        } else {
+            Err(meta.error("expected `width`, `align` or `symbol`"))
+        }
+    })?;
+
+    let width: usize =
+        width.ok_or_else(|| syn::Error::new_spanned(attribute, "missing `width`"))?;
+    Ok(Options {
+        width,
+        align,
+        symbol,
+    })
+}
+
+/// Derive `padder::FixedWidth` for a struct with named fields.
+///
+/// Every field needs a `#[fixed_width(width = ..)]` attribute and a type implementing
+/// `padder::FixedWidthField`. The optional `align` is one of `"left"`, `"right"` or
+/// `"center"`, and the optional `symbol` is the lowercase name of a `padder::Symbol`,
+/// like `"zero"` or `"whitespace"`, defaulting to `"right"` and `"whitespace"`.
+#[proc_macro_derive(FixedWidth, attributes(fixed_width))]
+pub fn derive_fixed_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "FixedWidth can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FixedWidth can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut widths = Vec::with_capacity(fields.len());
+    let mut writes = Vec::with_capacity(fields.len());
+    let mut reads = Vec::with_capacity(fields.len());
+    let mut constructs = Vec::with_capacity(fields.len());
+    let mut offset = quote!(0usize);
+
+    for (index, field) in fields.iter().enumerate() {
+        let Options {
+            width,
+            align,
+            symbol,
+        } = parse_options(field)?;
+        let ident: &Ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let label: String = ident.unraw().to_string();
+        let start = format_ident!("__padder_start_{}", index);
+        let value = format_ident!("__padder_field_{}", index);
+
+        widths.push(quote!(#width));
+        writes.push(quote! {
+            if let ::std::result::Result::Err(error) = ::padder::FixedWidthField::write_field(
+                &self.#ident,
+                #width,
+                ::padder::Alignment::#align,
+                ::padder::Symbol::#symbol,
+                __padder_buffer,
+            ) {
+                __padder_buffer.truncate(__padder_length);
+                return ::std::result::Result::Err(::padder::PadderError::Field {
+                    name: #label.to_string(),
+                    error: ::std::boxed::Box::new(error),
+                });
+            }
+        });
+        reads.push(quote! {
+            let #start: usize = #offset;
+            let #value: #ty = <#ty as ::padder::FixedWidthField>::read_field(
+                &__padder_record[#start..#start + #width],
+                ::padder::Alignment::#align,
+                ::padder::Symbol::#symbol,
+            )
+            .map_err(|error| ::padder::PadderError::Field {
+                name: #label.to_string(),
+                error: ::std::boxed::Box::new(error),
+            })?;
+        });
+        offset = quote!(#start + #width);
+        constructs.push(quote!(#ident: #value));
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::padder::FixedWidth for #name #ty_generics #where_clause {
+            const WIDTH: usize = 0 #(+ #widths)*;
+
+            fn to_fixed_width(
+                &self,
+                __padder_buffer: &mut ::std::vec::Vec<u8>,
+            ) -> ::std::result::Result<(), ::padder::PadderError> {
+                let __padder_length: usize = __padder_buffer.len();
+                #(#writes)*
+                ::std::result::Result::Ok(())
+            }
+
+            fn from_fixed_width(
+                __padder_record: &[u8],
+            ) -> ::std::result::Result<Self, ::padder::PadderError> {
+                if __padder_record.len() != <Self as ::padder::FixedWidth>::WIDTH {
+                    return ::std::result::Result::Err(::padder::PadderError::RecordLength {
+                        expected: <Self as ::padder::FixedWidth>::WIDTH,
+                        found: __padder_record.len(),
+                    });
+                }
+                #(#reads)*
+                ::std::result::Result::Ok(Self { #(#constructs),* })
+            }
+        }
+    })
+}
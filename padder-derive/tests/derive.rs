@@ -0,0 +1,165 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use padder::*;
+
+#[derive(FixedWidth, Debug, PartialEq)]
+struct Account {
+    #[fixed_width(width = 6, align = "right", symbol = "zero")]
+    id: u32,
+    #[fixed_width(width = 10, align = "left")]
+    name: String,
+    #[fixed_width(width = 8, align = "right", symbol = "zero")]
+    balance: i64,
+    #[fixed_width(width = 1, align = "left", symbol = "whitespace")]
+    status: char,
+    #[fixed_width(width = 4)]
+    branch: Option<u16>,
+}
+
+fn account() -> Account {
+    Account {
+        id: 42,
+        name: "Jane Doe".to_string(),
+        balance: -1250,
+        status: 'A',
+        branch: None,
+    }
+}
+
+#[test]
+fn derive_width() {
+    assert_eq!(29, Account::WIDTH);
+}
+
+#[test]
+fn derive_to_fixed_width() {
+    let mut buffer: Vec<u8> = Vec::with_capacity(Account::WIDTH);
+    account().to_fixed_width(&mut buffer).unwrap();
+    assert_eq!(b"000042Jane Doe  -0001250A    ", buffer.as_slice());
+}
+
+#[test]
+fn derive_round_trip() {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut expected = account();
+    expected.branch = Some(17);
+    expected.to_fixed_width(&mut buffer).unwrap();
+    assert_eq!(Ok(expected), Account::from_fixed_width(&buffer));
+}
+
+#[test]
+fn derive_from_short_record() {
+    let output = Account::from_fixed_width(b"000042");
+    assert_eq!(
+        Err(PadderError::RecordLength {
+            expected: 29,
+            found: 6,
+        }),
+        output
+    );
+}
+
+#[test]
+fn derive_from_long_record() {
+    let output = Account::from_fixed_width(b"000042Jane Doe  -0001250A    x");
+    assert_eq!(
+        Err(PadderError::RecordLength {
+            expected: 29,
+            found: 30,
+        }),
+        output
+    );
+}
+
+#[test]
+fn derive_to_overflowing_field() {
+    let mut buffer: Vec<u8> = b"prefix".to_vec();
+    let mut account = account();
+    account.balance = -12345678;
+    let output = account.to_fixed_width(&mut buffer);
+    assert_eq!(
+        Err(PadderError::Field {
+            name: "balance".to_string(),
+            error: Box::new(PadderError::Overflow {
+                width: 8,
+                required: 9,
+            }),
+        }),
+        output
+    );
+    assert_eq!(b"prefix", buffer.as_slice());
+}
+
+#[test]
+fn derive_from_invalid_field() {
+    let output = Account::from_fixed_width(b"0000x2Jane Doe  -0001250A    ");
+    assert!(matches!(
+        output,
+        Err(PadderError::Field { ref name, .. }) if name == "id"
+    ));
+}
+
+#[derive(FixedWidth, Debug, PartialEq)]
+struct Centered {
+    #[fixed_width(width = 7, align = "center", symbol = "hyphen")]
+    title: String,
+}
+
+#[test]
+fn derive_center_alignment() {
+    let mut buffer: Vec<u8> = Vec::new();
+    let centered = Centered {
+        title: "abc".to_string(),
+    };
+    centered.to_fixed_width(&mut buffer).unwrap();
+    assert_eq!(b"--abc--", buffer.as_slice());
+    assert_eq!(Ok(centered), Centered::from_fixed_width(&buffer));
+}
+
+#[derive(FixedWidth, Debug, PartialEq)]
+struct Shadowing {
+    #[fixed_width(width = 3, align = "left")]
+    record: String,
+    #[fixed_width(width = 3, align = "left")]
+    buffer: String,
+    #[fixed_width(width = 2, symbol = "zero")]
+    __length: u8,
+}
+
+#[test]
+fn derive_fields_named_like_locals() {
+    let mut buffer: Vec<u8> = Vec::new();
+    let shadowing = Shadowing {
+        record: "ab".to_string(),
+        buffer: "cd".to_string(),
+        __length: 7,
+    };
+    shadowing.to_fixed_width(&mut buffer).unwrap();
+    assert_eq!(b"ab cd 07", buffer.as_slice());
+    assert_eq!(Ok(shadowing), Shadowing::from_fixed_width(&buffer));
+}
@@ -0,0 +1,1261 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Conversion between Apache Arrow record batches and fixed-width records.
+//!
+//! A [`BatchWriter`] writes [`RecordBatch`]es with a [`Layout`] that holds the padding
+//! spec of each column, taking the column with the same name as each field of the
+//! layout. The records of a batch are encoded column by column, where strings and
+//! binaries are padded directly from the Arrow buffers and numbers are formatted
+//! without any intermediate strings.
+//!
+//! A [`BatchReader`] parses fixed-width records into record batches in the other
+//! direction, slicing each record at the offsets of the layout, stripping the fill
+//! [`Symbol`](crate::Symbol) and appending the decoded values to typed builders. The
+//! input, which can be a memory-mapped file, is split into chunks of whole records that
+//! are parsed in parallel.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use arrow_array::{ArrayRef, Decimal128Array, RecordBatch, StringArray};
+//! use arrow_schema::{DataType, Field as ArrowField, Schema};
+//! use padder::*;
+//!
+//! let schema = Arc::new(Schema::new(vec![
+//!     ArrowField::new("name", DataType::Utf8, true),
+//!     ArrowField::new("amount", DataType::Decimal128(10, 2), false),
+//! ]));
+//! let layout = Layout::new(vec![
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//!     Field::new("name", 4, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//! ]);
+//!
+//! let columns: Vec<ArrayRef> = vec![
+//!     Arc::new(StringArray::from(vec![Some("acme"), None])),
+//!     Arc::new(Decimal128Array::from(vec![1250, 300]).with_precision_and_scale(10, 2).unwrap()),
+//! ];
+//! let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+//!
+//! let mut writer = BatchWriter::new(Vec::new(), schema, layout.clone()).unwrap();
+//! writer.write(&batch).unwrap();
+//! assert_eq!(b"001250acme\n000300    \n", writer.finish().unwrap().as_slice());
+//!
+//! let reader = BatchReader::new(layout).unwrap();
+//! let batches: Vec<RecordBatch> = reader.read(b"001250acme\n000300    \n").unwrap();
+//! assert_eq!(2, batches[0].num_rows());
+//! assert_eq!(&DataType::Decimal128(38, 2), batches[0].column(0).data_type());
+//! ```
+//!
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BooleanBuilder, Date32Builder, Decimal128Builder, Int64Builder, StringBuilder,
+    Time32SecondBuilder, TimestampSecondBuilder,
+};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Decimal128Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::{
+    DataType, Field as ArrowField, Schema, SchemaRef, TimeUnit, DECIMAL128_MAX_PRECISION,
+    DECIMAL128_MAX_SCALE,
+};
+use memmap2::Mmap;
+use rayon::prelude::*;
+
+use crate::datetime::DateTime;
+use crate::layout::{Field, FieldKind, Layout};
+use crate::value::Value;
+use crate::writer::Terminator;
+use crate::PadderError;
+
+/// Check that a column of the data type can be converted to and from values.
+fn check_data_type(name: &str, data_type: &DataType) -> Result<(), PadderError> {
+    match data_type {
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Boolean
+        | DataType::Date32
+        | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+        | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        | DataType::Timestamp(..) => Ok(()),
+        DataType::Decimal128(_, scale) if *scale >= 0 => Ok(()),
+        data_type => Err(PadderError::InvalidLayout(format!(
+            "column '{}' has unsupported data type {}",
+            name, data_type
+        ))),
+    }
+}
+
+/// Get the time of the day from the number of seconds since midnight.
+fn time_of_day<'a>(seconds: i64) -> Result<Value<'a>, PadderError> {
+    if !(0..86_400).contains(&seconds) {
+        return Err(PadderError::OutOfRange);
+    }
+    let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    Ok(Value::DateTime(DateTime::time(
+        hour as u8,
+        minute as u8,
+        second as u8,
+    )))
+}
+
+/// Encode every value of a column with the field and push them to the column buffer,
+/// returning the row and error of the first value that fails.
+fn encode_values<'a>(
+    field: &Field,
+    array: &'a dyn Array,
+    column: &mut Vec<u8>,
+    value: impl Fn(usize) -> Result<Value<'a>, PadderError>,
+) -> Result<(), (usize, PadderError)> {
+    for row in 0..array.len() {
+        let value: Value = match array.is_null(row) {
+            true => Value::Null,
+            false => value(row).map_err(|error| (row, error))?,
+        };
+        field.write(&value, column).map_err(|error| (row, error))?;
+    }
+    Ok(())
+}
+
+/// Encode every value of a column with the field and push them to the column buffer,
+/// downcasting the array once for the whole column.
+fn encode_column(
+    field: &Field,
+    array: &dyn Array,
+    column: &mut Vec<u8>,
+) -> Result<(), (usize, PadderError)> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Text(Cow::Borrowed(array.value(row))))
+            })
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Text(Cow::Borrowed(array.value(row))))
+            })
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Bytes(Cow::Borrowed(array.value(row))))
+            })
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Bytes(Cow::Borrowed(array.value(row))))
+            })
+        }
+        DataType::Int8 => {
+            let array = array.as_primitive::<Int8Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::Int16 => {
+            let array = array.as_primitive::<Int16Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::Int32 => {
+            let array = array.as_primitive::<Int32Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::Int64 => {
+            let array = array.as_primitive::<Int64Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row)))
+            })
+        }
+        DataType::UInt8 => {
+            let array = array.as_primitive::<UInt8Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::UInt16 => {
+            let array = array.as_primitive::<UInt16Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::UInt32 => {
+            let array = array.as_primitive::<UInt32Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Integer(array.value(row) as i64))
+            })
+        }
+        DataType::UInt64 => {
+            let array = array.as_primitive::<UInt64Type>();
+            encode_values(field, array, column, |row| {
+                let value: i64 =
+                    i64::try_from(array.value(row)).map_err(|_| PadderError::OutOfRange)?;
+                Ok(Value::Integer(value))
+            })
+        }
+        DataType::Float32 => {
+            let array = array.as_primitive::<Float32Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Float(array.value(row) as f64))
+            })
+        }
+        DataType::Float64 => {
+            let array = array.as_primitive::<Float64Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Float(array.value(row)))
+            })
+        }
+        DataType::Decimal128(_, scale) => {
+            let array = array.as_primitive::<Decimal128Type>();
+            encode_values(field, array, column, |row| {
+                let value: i64 =
+                    i64::try_from(array.value(row)).map_err(|_| PadderError::OutOfRange)?;
+                Ok(Value::Decimal(value, *scale as u32))
+            })
+        }
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            encode_values(field, array, column, |row| {
+                Ok(Value::Boolean(array.value(row)))
+            })
+        }
+        DataType::Date32 => {
+            let array = array.as_primitive::<Date32Type>();
+            encode_values(field, array, column, |row| {
+                Ok(Value::DateTime(DateTime::from_unix_days(
+                    array.value(row) as i64
+                )?))
+            })
+        }
+        DataType::Timestamp(unit, _) => {
+            let (values, per_second): (&[i64], i64) = match unit {
+                TimeUnit::Second => (array.as_primitive::<TimestampSecondType>().values(), 1),
+                TimeUnit::Millisecond => (
+                    array.as_primitive::<TimestampMillisecondType>().values(),
+                    1_000,
+                ),
+                TimeUnit::Microsecond => (
+                    array.as_primitive::<TimestampMicrosecondType>().values(),
+                    1_000_000,
+                ),
+                TimeUnit::Nanosecond => (
+                    array.as_primitive::<TimestampNanosecondType>().values(),
+                    1_000_000_000,
+                ),
+            };
+            encode_values(field, array, column, |row| {
+                let seconds: i64 = values[row].div_euclid(per_second);
+                Ok(Value::DateTime(DateTime::from_unix_seconds(seconds)?))
+            })
+        }
+        DataType::Time32(unit) => {
+            let (values, per_second): (&[i32], i32) = match unit {
+                TimeUnit::Millisecond => (
+                    array.as_primitive::<Time32MillisecondType>().values(),
+                    1_000,
+                ),
+                _ => (array.as_primitive::<Time32SecondType>().values(), 1),
+            };
+            encode_values(field, array, column, |row| {
+                time_of_day((values[row] / per_second) as i64)
+            })
+        }
+        DataType::Time64(unit) => {
+            let (values, per_second): (&[i64], i64) = match unit {
+                TimeUnit::Nanosecond => (
+                    array.as_primitive::<Time64NanosecondType>().values(),
+                    1_000_000_000,
+                ),
+                _ => (
+                    array.as_primitive::<Time64MicrosecondType>().values(),
+                    1_000_000,
+                ),
+            };
+            encode_values(field, array, column, |row| {
+                time_of_day(values[row] / per_second)
+            })
+        }
+        data_type => Err((0, PadderError::InvalidValue(data_type.to_string()))),
+    }
+}
+
+/// A writer of Arrow record batches as fixed-width records.
+pub struct BatchWriter<W: io::Write> {
+    writer: W,
+    schema: SchemaRef,
+    layout: Layout,
+    /// The index in the schema of the column of each field of the layout.
+    columns: Vec<usize>,
+    terminator: Terminator,
+    count: u64,
+    column: Vec<u8>,
+    buffer: Vec<u8>,
+}
+
+impl<W: io::Write> BatchWriter<W> {
+    /// Create a new [`BatchWriter`] that writes batches with the schema as records with the
+    /// layout, each followed by a line feed.
+    ///
+    /// Returns an error if a field of the layout has no column with the same name in the
+    /// schema, or if the data type of that column is not supported.
+    pub fn new(writer: W, schema: SchemaRef, layout: Layout) -> Result<Self, PadderError> {
+        layout.validate()?;
+
+        let mut columns: Vec<usize> = Vec::with_capacity(layout.fields().len());
+        for field in layout.fields() {
+            let (index, column) = schema.column_with_name(&field.name).ok_or_else(|| {
+                PadderError::InvalidLayout(format!("no column named '{}'", field.name))
+            })?;
+            check_data_type(column.name(), column.data_type())?;
+            columns.push(index);
+        }
+
+        Ok(Self {
+            writer,
+            schema,
+            layout,
+            columns,
+            terminator: Terminator::default(),
+            count: 0,
+            column: Vec::new(),
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Set the terminator written after each record.
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Get the number of records written.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Write every row of the batch as a record.
+    ///
+    /// If any value fails to be written, an error with the line number and byte offset
+    /// of its record is returned and nothing of the batch is written.
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), PadderError> {
+        if batch.schema_ref().fields() != self.schema.fields() {
+            return Err(PadderError::InvalidValue(
+                "record batch does not match the schema of the writer".to_string(),
+            ));
+        }
+
+        let terminator: &[u8] = self.terminator.as_bytes();
+        let width: usize = self.layout.width() + terminator.len();
+        self.buffer.clear();
+        self.buffer.resize(batch.num_rows() * width, 0);
+
+        let mut offset: usize = 0;
+        for (field, index) in self.layout.fields().iter().zip(self.columns.iter()) {
+            self.column.clear();
+            encode_column(field, batch.column(*index), &mut self.column).map_err(
+                |(row, error)| PadderError::Record {
+                    line: self.count + row as u64 + 1,
+                    offset: (self.count + row as u64) * width as u64,
+                    error: Box::new(PadderError::Field {
+                        name: field.name.clone(),
+                        error: Box::new(error),
+                    }),
+                },
+            )?;
+
+            for (record, value) in self
+                .buffer
+                .chunks_exact_mut(width)
+                .zip(self.column.chunks_exact(field.width))
+            {
+                record[offset..offset + field.width].copy_from_slice(value);
+            }
+            offset += field.width;
+        }
+
+        for record in self.buffer.chunks_exact_mut(width) {
+            record[offset..].copy_from_slice(terminator);
+        }
+
+        self.writer.write_all(&self.buffer)?;
+        self.count += batch.num_rows() as u64;
+        Ok(())
+    }
+
+    /// Flush the underlying writer and return it.
+    pub fn finish(mut self) -> Result<W, PadderError> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Get the data type of the column that a field is decoded into.
+fn field_data_type(field: &Field) -> Result<DataType, PadderError> {
+    Ok(match &field.kind {
+        FieldKind::Text => DataType::Utf8,
+        FieldKind::Boolean(_) => DataType::Boolean,
+        FieldKind::DateTime(format) if !format.has_time() => DataType::Date32,
+        FieldKind::DateTime(format) if !format.has_date() => DataType::Time32(TimeUnit::Second),
+        FieldKind::DateTime(_) => DataType::Timestamp(TimeUnit::Second, None),
+        _ if field.scale == 0 => DataType::Int64,
+        _ => {
+            let scale: i8 = i8::try_from(field.scale)
+                .ok()
+                .filter(|s| *s <= DECIMAL128_MAX_SCALE)
+                .ok_or_else(|| PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(PadderError::OutOfRange),
+                })?;
+            DataType::Decimal128(DECIMAL128_MAX_PRECISION, scale)
+        }
+    })
+}
+
+/// A builder of the array of a column, for each data type that fields are decoded into.
+enum ColumnBuilder {
+    Utf8(StringBuilder),
+    Int64(Int64Builder),
+    Decimal128(Decimal128Builder, u32),
+    Boolean(BooleanBuilder),
+    Date32(Date32Builder),
+    Time32(Time32SecondBuilder),
+    Timestamp(TimestampSecondBuilder),
+}
+
+impl ColumnBuilder {
+    /// Create a new [`ColumnBuilder`] for a column of the data type.
+    fn new(data_type: &DataType, capacity: usize) -> Self {
+        match data_type {
+            DataType::Utf8 => Self::Utf8(StringBuilder::with_capacity(capacity, capacity * 8)),
+            DataType::Boolean => Self::Boolean(BooleanBuilder::with_capacity(capacity)),
+            DataType::Date32 => Self::Date32(Date32Builder::with_capacity(capacity)),
+            DataType::Time32(_) => Self::Time32(Time32SecondBuilder::with_capacity(capacity)),
+            DataType::Timestamp(..) => {
+                Self::Timestamp(TimestampSecondBuilder::with_capacity(capacity))
+            }
+            DataType::Decimal128(_, scale) => Self::Decimal128(
+                Decimal128Builder::with_capacity(capacity).with_data_type(data_type.clone()),
+                *scale as u32,
+            ),
+            _ => Self::Int64(Int64Builder::with_capacity(capacity)),
+        }
+    }
+
+    /// Append a decoded value to the column.
+    fn append(&mut self, value: &Value) -> Result<(), PadderError> {
+        let datetime = || match value {
+            Value::Null => Ok(None),
+            Value::DateTime(datetime) => Ok(Some(*datetime)),
+            _ => Err(PadderError::InvalidDateTime),
+        };
+
+        match self {
+            Self::Utf8(builder) => match value {
+                Value::Null => builder.append_null(),
+                Value::Text(text) => builder.append_value(text),
+                Value::Bytes(_) => {
+                    return Err(PadderError::InvalidValue(
+                        "field is not valid UTF-8".to_string(),
+                    ))
+                }
+                value => builder.append_value(value.to_string()),
+            },
+            Self::Int64(builder) => builder.append_option(value.to_scaled(0)?),
+            Self::Decimal128(builder, scale) => {
+                builder.append_option(value.to_scaled(*scale)?.map(i128::from))
+            }
+            Self::Boolean(builder) => match value {
+                Value::Null => builder.append_null(),
+                Value::Boolean(boolean) => builder.append_value(*boolean),
+                value => return Err(PadderError::UnknownCode(value.to_string())),
+            },
+            Self::Date32(builder) => match datetime()? {
+                None => builder.append_null(),
+                Some(datetime) => {
+                    let days: i32 = i32::try_from(datetime.unix_days()?)
+                        .map_err(|_| PadderError::OutOfRange)?;
+                    builder.append_value(days)
+                }
+            },
+            Self::Time32(builder) => match datetime()? {
+                None => builder.append_null(),
+                Some(datetime) => builder.append_value(
+                    datetime.hour as i32 * 3600
+                        + datetime.minute as i32 * 60
+                        + datetime.second as i32,
+                ),
+            },
+            Self::Timestamp(builder) => match datetime()? {
+                None => builder.append_null(),
+                Some(datetime) => builder.append_value(datetime.unix_seconds()?),
+            },
+        }
+        Ok(())
+    }
+
+    /// Build the array of the appended values.
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Self::Utf8(builder) => Arc::new(builder.finish()),
+            Self::Int64(builder) => Arc::new(builder.finish()),
+            Self::Decimal128(builder, _) => Arc::new(builder.finish()),
+            Self::Boolean(builder) => Arc::new(builder.finish()),
+            Self::Date32(builder) => Arc::new(builder.finish()),
+            Self::Time32(builder) => Arc::new(builder.finish()),
+            Self::Timestamp(builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+/// A parallel reader of fixed-width records into Arrow record batches.
+///
+/// The input is split into chunks of whole records, which are parsed into one record
+/// batch each on the rayon thread pool. Every field of the layout is decoded into a
+/// nullable column of the schema given by [`BatchReader::schema`].
+pub struct BatchReader {
+    layout: Layout,
+    schema: SchemaRef,
+    terminator: Terminator,
+    batch_size: usize,
+}
+
+impl BatchReader {
+    /// Create a new [`BatchReader`] that reads records with the layout, each followed by
+    /// a line feed or a carriage return and line feed, into batches of 8192 records.
+    ///
+    /// Text fields are decoded into `Utf8` columns, numeric fields into `Int64` columns,
+    /// or `Decimal128` columns if they have implied decimals, boolean fields into
+    /// `Boolean` columns, and date and time fields into `Date32`, `Time32` or `Timestamp`
+    /// columns depending on the components of their format.
+    pub fn new(layout: Layout) -> Result<Self, PadderError> {
+        layout.validate()?;
+
+        let fields: Vec<ArrowField> = layout
+            .fields()
+            .iter()
+            .map(|f| Ok(ArrowField::new(&f.name, field_data_type(f)?, true)))
+            .collect::<Result<_, PadderError>>()?;
+
+        Ok(Self {
+            layout,
+            schema: Arc::new(Schema::new(fields)),
+            terminator: Terminator::default(),
+            batch_size: 8192,
+        })
+    }
+
+    /// Set the terminator after each record, where [`Terminator::Lf`] and
+    /// [`Terminator::CrLf`] both accept either terminator.
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Set the maximum number of records in each batch, which is also the unit of work
+    /// of each thread.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Get the schema of the record batches.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Split the input into chunks of at most the batch size of whole records.
+    fn chunks(&self, data: &[u8]) -> Vec<Range<usize>> {
+        let width: usize = self.layout.width();
+        let size: usize = match self.terminator {
+            Terminator::None => self.batch_size * width,
+            _ => self.batch_size * (width + 1),
+        };
+
+        let mut chunks: Vec<Range<usize>> = Vec::with_capacity(data.len() / size + 1);
+        let mut start: usize = 0;
+        while start < data.len() {
+            let mut end: usize = (start + size).min(data.len());
+            if self.terminator != Terminator::None {
+                // Include the rest of the line, and let the line feed end the chunk.
+                end = match data[end - 1..].iter().position(|b| *b == b'\n') {
+                    Some(position) => end + position,
+                    None => data.len(),
+                };
+            }
+            chunks.push(start..end);
+            start = end;
+        }
+        chunks
+    }
+
+    /// Get the line number of the record at the byte offset of the input.
+    fn line(&self, data: &[u8], offset: usize) -> u64 {
+        match self.terminator {
+            Terminator::None => (offset / self.layout.width()) as u64 + 1,
+            _ => data[..offset].iter().filter(|b| **b == b'\n').count() as u64 + 1,
+        }
+    }
+
+    /// Parse the records of a chunk of the input into a record batch.
+    fn read_chunk(&self, data: &[u8], chunk: Range<usize>) -> Result<RecordBatch, PadderError> {
+        let mut records: Vec<(usize, &[u8])> = Vec::with_capacity(self.batch_size);
+        match self.terminator {
+            Terminator::None => {
+                let width: usize = self.layout.width();
+                for (index, record) in data[chunk.clone()].chunks(width).enumerate() {
+                    records.push((chunk.start + index * width, record));
+                }
+            }
+            _ => {
+                let mut offset: usize = chunk.start;
+                for line in data[chunk].split_inclusive(|b| *b == b'\n') {
+                    let record: &[u8] = line.strip_suffix(b"\n").unwrap_or(line);
+                    let record: &[u8] = record.strip_suffix(b"\r").unwrap_or(record);
+                    if !record.is_empty() {
+                        records.push((offset, record));
+                    }
+                    offset += line.len();
+                }
+            }
+        }
+
+        let mut builders: Vec<ColumnBuilder> = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::new(f.data_type(), records.len()))
+            .collect();
+
+        for (offset, record) in records {
+            let at = |error: PadderError| PadderError::Record {
+                line: self.line(data, offset),
+                offset: offset as u64,
+                error: Box::new(error),
+            };
+
+            let fields: Vec<&[u8]> = self.layout.split(record).map_err(at)?;
+            for ((field, raw), builder) in self
+                .layout
+                .fields()
+                .iter()
+                .zip(fields)
+                .zip(builders.iter_mut())
+            {
+                field
+                    .decode(raw)
+                    .and_then(|value| builder.append(&value))
+                    .map_err(|error| {
+                        at(PadderError::Field {
+                            name: field.name.clone(),
+                            error: Box::new(error),
+                        })
+                    })?;
+            }
+        }
+
+        let columns: Vec<ArrayRef> = builders.iter_mut().map(ColumnBuilder::finish).collect();
+        RecordBatch::try_new(self.schema.clone(), columns)
+            .map_err(|error| PadderError::InvalidValue(error.to_string()))
+    }
+
+    /// Parse the records of the input into record batches in parallel.
+    ///
+    /// If any record fails to be parsed, the error of the first such record in the input
+    /// is returned, with its line number and byte offset.
+    pub fn read(&self, data: &[u8]) -> Result<Vec<RecordBatch>, PadderError> {
+        let batches: Vec<Result<RecordBatch, PadderError>> = self
+            .chunks(data)
+            .into_par_iter()
+            .map(|chunk| self.read_chunk(data, chunk))
+            .collect();
+        batches
+            .into_iter()
+            .filter(|b| !matches!(b, Ok(b) if b.num_rows() == 0))
+            .collect()
+    }
+
+    /// Parse the records of the input into record batches in parallel and pass each of
+    /// them in order to the callback, returning the number of records parsed.
+    ///
+    /// Unlike [`BatchReader::read`], only about one batch per thread is held in memory at
+    /// a time. If any record fails to be parsed, or the callback fails, the first error
+    /// is returned and the remaining input is not parsed.
+    pub fn for_each_batch<F>(&self, data: &[u8], mut callback: F) -> Result<u64, PadderError>
+    where
+        F: FnMut(RecordBatch) -> Result<(), PadderError>,
+    {
+        let mut count: u64 = 0;
+        let chunks: Vec<Range<usize>> = self.chunks(data);
+        for group in chunks.chunks(rayon::current_num_threads()) {
+            let batches: Vec<Result<RecordBatch, PadderError>> = group
+                .par_iter()
+                .map(|chunk| self.read_chunk(data, chunk.clone()))
+                .collect();
+            for batch in batches {
+                let batch: RecordBatch = batch?;
+                if batch.num_rows() > 0 {
+                    count += batch.num_rows() as u64;
+                    callback(batch)?;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Memory-map the file at the path and parse its records into record batches in
+    /// parallel, see [`BatchReader::read`].
+    pub fn read_path<P: AsRef<Path>>(&self, path: P) -> Result<Vec<RecordBatch>, PadderError> {
+        let file = File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Ok(Vec::new());
+        }
+        // SAFETY: The map is only read while it is alive, and the file is assumed not to
+        // be modified by other processes while it is being read.
+        let data: Mmap = unsafe { Mmap::map(&file)? };
+        self.read(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alignment, BoolCodes, DateTimeFormat, Encoding, Symbol};
+
+    use arrow_array::{
+        ArrayRef, BinaryArray, BooleanArray, Date32Array, Float64Array, Int32Array,
+        LargeStringArray, TimestampMillisecondArray, UInt64Array,
+    };
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            ArrowField::new("id", DataType::Int32, false),
+            ArrowField::new("name", DataType::LargeUtf8, true),
+            ArrowField::new("raw", DataType::Binary, true),
+            ArrowField::new("rate", DataType::Float64, true),
+            ArrowField::new("flag", DataType::Boolean, true),
+            ArrowField::new("day", DataType::Date32, true),
+            ArrowField::new("at", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        ]))
+    }
+
+    fn batch() -> RecordBatch {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![7, -3])),
+            Arc::new(LargeStringArray::from(vec![Some("abc"), None])),
+            Arc::new(BinaryArray::from(vec![Some(b"xy".as_slice()), None])),
+            Arc::new(Float64Array::from(vec![Some(1.25), None])),
+            Arc::new(BooleanArray::from(vec![Some(true), Some(false)])),
+            Arc::new(Date32Array::from(vec![Some(19_853), None])),
+            Arc::new(TimestampMillisecondArray::from(vec![
+                Some(1_715_329_801_999),
+                Some(-1),
+            ])),
+        ];
+        RecordBatch::try_new(schema(), columns).unwrap()
+    }
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new(
+                "at",
+                14,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDDHHMMSS")),
+            ),
+            Field::new(
+                "id",
+                4,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Signed(Encoding::Ascii),
+            ),
+            Field::new("name", 4, Alignment::Left, Symbol::Hyphen, FieldKind::Text),
+            Field::new(
+                "raw",
+                3,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "rate",
+                5,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(3),
+            Field::new(
+                "flag",
+                5,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "day",
+                8,
+                Alignment::Left,
+                Symbol::Zero,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+        ])
+    }
+
+    #[test]
+    fn write_batches() {
+        let mut writer = BatchWriter::new(Vec::new(), schema(), layout())
+            .unwrap()
+            .with_terminator(Terminator::CrLf);
+        writer.write(&batch()).unwrap();
+        writer.write(&batch().slice(1, 1)).unwrap();
+        assert_eq!(3, writer.count());
+
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let lines: Vec<&str> = output.split_terminator("\r\n").collect();
+        assert_eq!(
+            vec![
+                "20240510083001000Gabc- xy01250true 20240510",
+                "19691231235959000L----   00000false00000000",
+                "19691231235959000L----   00000false00000000",
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn write_without_terminator() {
+        let schema = Arc::new(Schema::new(vec![ArrowField::new(
+            "id",
+            DataType::UInt64,
+            false,
+        )]));
+        let layout = Layout::new(vec![Field::new(
+            "id",
+            3,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )]);
+        let column: ArrayRef = Arc::new(UInt64Array::from(vec![1, 22, 333]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![column]).unwrap();
+
+        let mut writer = BatchWriter::new(Vec::new(), schema, layout)
+            .unwrap()
+            .with_terminator(Terminator::None);
+        writer.write(&batch).unwrap();
+        assert_eq!(b"001022333", writer.finish().unwrap().as_slice());
+    }
+
+    #[test]
+    fn new_errors() {
+        let numeric = |name: &str| {
+            Layout::new(vec![Field::new(
+                name,
+                3,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )])
+        };
+        let output = BatchWriter::new(Vec::new(), schema(), numeric("missing"));
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+
+        let half = Arc::new(Schema::new(vec![ArrowField::new(
+            "id",
+            DataType::Float16,
+            false,
+        )]));
+        let output = BatchWriter::new(Vec::new(), half, numeric("id"));
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+    }
+
+    #[test]
+    fn write_errors() {
+        let fields: Vec<Field> = layout()
+            .fields()
+            .iter()
+            .map(|f| match f.name.as_str() {
+                "id" => Field::new("id", 4, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+                _ => f.clone(),
+            })
+            .collect();
+        let mut writer = BatchWriter::new(Vec::new(), schema(), Layout::new(fields)).unwrap();
+        writer.write(&batch().slice(0, 1)).unwrap();
+        let output = writer.write(&batch());
+        assert!(matches!(
+            output,
+            Err(PadderError::Record { line: 3, offset: 88, ref error })
+                if matches!(**error, PadderError::Field { ref name, .. } if name == "id")
+        ));
+        assert_eq!(1, writer.count());
+        assert_eq!(44, writer.finish().unwrap().len());
+
+        let other = Arc::new(Schema::new(vec![ArrowField::new(
+            "id",
+            DataType::Int32,
+            false,
+        )]));
+        let column: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let batch = RecordBatch::try_new(other, vec![column]).unwrap();
+        let mut writer = BatchWriter::new(Vec::new(), schema(), layout()).unwrap();
+        assert!(matches!(
+            writer.write(&batch),
+            Err(PadderError::InvalidValue(_))
+        ));
+    }
+
+    fn records() -> Layout {
+        let datetime = |pattern: &str| FieldKind::DateTime(DateTimeFormat::new(pattern));
+        Layout::new(vec![
+            Field::new(
+                "name",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "amount",
+                6,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+            Field::new(
+                "delta",
+                3,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Signed(Encoding::Ascii),
+            ),
+            Field::new(
+                "flag",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+            Field::new(
+                "day",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                datetime("YYYYMMDD"),
+            ),
+            Field::new(
+                "at",
+                14,
+                Alignment::Left,
+                Symbol::Whitespace,
+                datetime("YYYYMMDDHHMMSS"),
+            ),
+            Field::new(
+                "time",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                datetime("HHMM"),
+            ),
+        ])
+    }
+
+    fn input(terminators: &[&[u8]]) -> Vec<u8> {
+        let layout = records();
+        let rows: Vec<Vec<Value>> = vec![
+            vec![
+                Value::from("acme"),
+                Value::from("12.50"),
+                Value::from(-12),
+                Value::from(true),
+                Value::from(DateTime::date(2024, 5, 10)),
+                Value::from(DateTime::new(2024, 5, 10, 8, 30, 1)),
+                Value::from(DateTime::time(8, 30, 0)),
+            ],
+            vec![
+                Value::from("b"),
+                Value::from(3),
+                Value::from(0),
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+            ],
+            vec![
+                Value::from(""),
+                Value::from(0),
+                Value::from(7),
+                Value::from(false),
+                Value::from(DateTime::date(1969, 12, 31)),
+                Value::from(DateTime::new(1970, 1, 1, 0, 0, 1)),
+                Value::from(DateTime::time(23, 59, 0)),
+            ],
+        ];
+
+        let mut data: Vec<u8> = Vec::new();
+        for (row, terminator) in rows.iter().zip(terminators.iter().cycle()) {
+            layout.write(row, &mut data).unwrap();
+            data.extend_from_slice(terminator);
+        }
+        data
+    }
+
+    /// Collect the values of the column at the index over all batches.
+    fn collect<'a, T>(
+        batches: &'a [RecordBatch],
+        index: usize,
+        values: impl Fn(&'a ArrayRef) -> Vec<T>,
+    ) -> Vec<T> {
+        batches
+            .iter()
+            .flat_map(|b| values(b.column(index)))
+            .collect()
+    }
+
+    fn check_batches(batches: &[RecordBatch]) {
+        let name = collect(batches, 0, |a| a.as_string::<i32>().iter().collect());
+        assert_eq!(vec![Some("acme"), Some("b"), Some("")], name);
+        let amount = collect(batches, 1, |a| {
+            a.as_primitive::<Decimal128Type>().iter().collect()
+        });
+        assert_eq!(vec![Some(1250), Some(300), Some(0)], amount);
+        let delta = collect(batches, 2, |a| {
+            a.as_primitive::<Int64Type>().iter().collect()
+        });
+        assert_eq!(vec![Some(-12), Some(0), Some(7)], delta);
+        let flag = collect(batches, 3, |a| a.as_boolean().iter().collect());
+        assert_eq!(vec![Some(true), None, Some(false)], flag);
+        let day = collect(batches, 4, |a| {
+            a.as_primitive::<Date32Type>().iter().collect()
+        });
+        assert_eq!(vec![Some(19_853), None, Some(-1)], day);
+        let at = collect(batches, 5, |a| {
+            a.as_primitive::<TimestampSecondType>().iter().collect()
+        });
+        assert_eq!(vec![Some(1_715_329_801), None, Some(1)], at);
+        let time = collect(batches, 6, |a| {
+            a.as_primitive::<Time32SecondType>().iter().collect()
+        });
+        assert_eq!(vec![Some(30_600), None, Some(86_340)], time);
+    }
+
+    #[test]
+    fn reader_schema() {
+        let reader = BatchReader::new(records()).unwrap();
+        let data_types: Vec<DataType> = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(
+            vec![
+                DataType::Utf8,
+                DataType::Decimal128(38, 2),
+                DataType::Int64,
+                DataType::Boolean,
+                DataType::Date32,
+                DataType::Timestamp(TimeUnit::Second, None),
+                DataType::Time32(TimeUnit::Second),
+            ],
+            data_types
+        );
+        assert!(reader.schema().fields().iter().all(|f| f.is_nullable()));
+    }
+
+    #[test]
+    fn read_lines() {
+        let data: Vec<u8> = input(&[b"\r\n", b"\n\n"]);
+        for batch_size in [1, 2, 3, 8192] {
+            let reader = BatchReader::new(records())
+                .unwrap()
+                .with_batch_size(batch_size);
+            let batches: Vec<RecordBatch> = reader.read(&data).unwrap();
+            assert_eq!(3usize.div_ceil(batch_size), batches.len());
+            check_batches(&batches);
+        }
+
+        let mut data: Vec<u8> = input(&[b"\n"]);
+        data.pop();
+        let batches = BatchReader::new(records()).unwrap().read(&data).unwrap();
+        check_batches(&batches);
+        assert!(BatchReader::new(records())
+            .unwrap()
+            .read(b"")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn read_blocks() {
+        let data: Vec<u8> = input(&[b""]);
+        let reader = BatchReader::new(records())
+            .unwrap()
+            .with_terminator(Terminator::None)
+            .with_batch_size(2);
+        let batches: Vec<RecordBatch> = reader.read(&data).unwrap();
+        assert_eq!(
+            vec![2, 1],
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>()
+        );
+        check_batches(&batches);
+
+        let output = reader.read(&data[..data.len() - 1]);
+        assert!(matches!(
+            output,
+            Err(PadderError::Record { line: 3, offset: 80, ref error })
+                if matches!(**error, PadderError::RecordLength { expected: 40, found: 39 })
+        ));
+    }
+
+    #[test]
+    fn read_errors() {
+        let mut data: Vec<u8> = input(&[b"\n", b"\n\n"]);
+        data[45] = b'x';
+        data[87] = b'x';
+        let reader = BatchReader::new(records()).unwrap().with_batch_size(1);
+        let output = reader.read(&data);
+        assert!(matches!(
+            output,
+            Err(PadderError::Record { line: 2, offset: 41, ref error })
+                if matches!(**error, PadderError::Field { ref name, .. } if name == "amount")
+        ));
+
+        let layout = Layout::new(vec![Field::new(
+            "day",
+            8,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+        )]);
+        let output = BatchReader::new(layout)
+            .unwrap()
+            .read(b"20240229\n20240230\n");
+        assert!(matches!(
+            output,
+            Err(PadderError::Record { line: 2, ref error, .. })
+                if matches!(**error, PadderError::Field { ref name, .. } if name == "day")
+        ));
+
+        let layout = Layout::new(vec![Field::new(
+            "id",
+            2,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )
+        .with_scale(40)]);
+        assert!(matches!(
+            BatchReader::new(layout),
+            Err(PadderError::Field { .. })
+        ));
+    }
+
+    #[test]
+    fn read_each_batch() {
+        let data: Vec<u8> = input(&[b"\n"]);
+        let reader = BatchReader::new(records()).unwrap().with_batch_size(1);
+        let mut batches: Vec<RecordBatch> = Vec::new();
+        let count = reader.for_each_batch(&data, |batch| {
+            batches.push(batch);
+            Ok(())
+        });
+        assert_eq!(Ok(3), count);
+        assert_eq!(3, batches.len());
+        check_batches(&batches);
+
+        let mut calls: usize = 0;
+        let output = reader.for_each_batch(&data, |_| {
+            calls += 1;
+            Err(PadderError::EmptyField)
+        });
+        assert_eq!((Err(PadderError::EmptyField), 1), (output, calls));
+
+        let mut data: Vec<u8> = data;
+        data[45] = b'x';
+        let output = reader.for_each_batch(&data, |_| Ok(()));
+        assert!(matches!(output, Err(PadderError::Record { line: 2, .. })));
+    }
+
+    #[test]
+    fn read_write_round_trip() {
+        let data: Vec<u8> = input(&[b"\n"]);
+        let reader = BatchReader::new(records()).unwrap().with_batch_size(2);
+        let mut writer = BatchWriter::new(Vec::new(), reader.schema(), records()).unwrap();
+        for batch in reader.read(&data).unwrap() {
+            writer.write(&batch).unwrap();
+        }
+        assert_eq!(data, writer.finish().unwrap());
+    }
+
+    #[test]
+    fn read_path() {
+        let path = std::env::temp_dir().join("padder_read_path.txt");
+        std::fs::write(&path, input(&[b"\n"])).unwrap();
+        let batches = BatchReader::new(records()).unwrap().read_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        check_batches(&batches.unwrap());
+
+        let output = BatchReader::new(records())
+            .unwrap()
+            .read_path(std::env::temp_dir().join("padder_missing.txt"));
+        assert!(matches!(output, Err(PadderError::Io { .. })));
+    }
+}
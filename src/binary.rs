@@ -0,0 +1,226 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Binary integer (COMP, COMP-5) encoding and decoding.
+//!
+//! Binary fields store an integer in two's complement using 1, 2, 4 or 8 bytes, in either
+//! big-endian or little-endian byte order. The fields are pushed to the same `Vec<u8>`
+//! buffer as the padded text fields, so that a single record can mix both.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(8);
+//! pad_and_push_to_buffer("abc".as_bytes(), 4, Alignment::Left, Symbol::Whitespace, &mut buffer);
+//! encode_binary(-2, 4, true, Endianness::Big, &mut buffer).unwrap();
+//! assert_eq!(b"abc \xFF\xFF\xFF\xFE", buffer.as_slice());
+//!
+//! let value: i128 = decode_binary(&buffer[4..], true, Endianness::Big).unwrap();
+//! assert_eq!(-2, value);
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::PadderError;
+
+/// Exhaustive enum for the byte orders of binary fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Endianness {
+    #[default]
+    Big,
+    Little,
+}
+
+/// Verify that the byte length is one of the supported binary field lengths.
+fn check_length(length: usize) -> Result<(), PadderError> {
+    match length {
+        1 | 2 | 4 | 8 => Ok(()),
+        _ => Err(PadderError::InvalidLength(length)),
+    }
+}
+
+/// Get the inclusive range of values representable in the given byte length.
+fn value_range(length: usize, signed: bool) -> (i128, i128) {
+    let bits: u32 = 8 * length as u32;
+    if signed {
+        (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+    } else {
+        (0, (1i128 << bits) - 1)
+    }
+}
+
+/// Encode an integer as a binary field of the given byte length and push it to the buffer.
+///
+/// The byte length has to be either 1, 2, 4 or 8, and the value has to fit in the
+/// field, otherwise an error is returned and nothing is pushed to the buffer.
+pub fn encode_binary(
+    value: i128,
+    length: usize,
+    signed: bool,
+    endianness: Endianness,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    check_length(length)?;
+
+    let (min, max) = value_range(length, signed);
+    if value < min || value > max {
+        return Err(PadderError::OutOfRange);
+    }
+
+    let bytes: [u8; 16] = value.to_be_bytes();
+    let field: &[u8] = &bytes[(bytes.len() - length)..];
+    match endianness {
+        Endianness::Big => buffer.extend_from_slice(field),
+        Endianness::Little => buffer.extend(field.iter().rev()),
+    }
+
+    Ok(())
+}
+
+/// Decode a binary field, where the byte length of the field has to be either 1, 2, 4 or 8.
+pub fn decode_binary(
+    field: &[u8],
+    signed: bool,
+    endianness: Endianness,
+) -> Result<i128, PadderError> {
+    check_length(field.len())?;
+
+    let mut bytes = [0u8; 16];
+    let start: usize = bytes.len() - field.len();
+    bytes[start..].copy_from_slice(field);
+    if endianness == Endianness::Little {
+        bytes[start..].reverse();
+    }
+
+    if signed && bytes[start] & 0x80 != 0 {
+        bytes[..start].fill(0xFF);
+    }
+
+    Ok(i128::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pad_and_push_to_buffer, Alignment, Symbol};
+
+    #[test]
+    fn encode_binary_big_endian() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_binary(0x0102, 2, false, Endianness::Big, &mut buffer).unwrap();
+        encode_binary(-1, 1, true, Endianness::Big, &mut buffer).unwrap();
+        assert_eq!(vec![0x01, 0x02, 0xFF], buffer);
+    }
+
+    #[test]
+    fn encode_binary_little_endian() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_binary(0x01020304, 4, false, Endianness::Little, &mut buffer).unwrap();
+        assert_eq!(vec![0x04, 0x03, 0x02, 0x01], buffer);
+    }
+
+    #[test]
+    fn encode_binary_limits() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_binary(u64::MAX as i128, 8, false, Endianness::Big, &mut buffer).unwrap();
+        encode_binary(i64::MIN as i128, 8, true, Endianness::Big, &mut buffer).unwrap();
+        let mut expected = vec![0xFF; 8];
+        expected.extend_from_slice(&[0x80, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(expected, buffer);
+    }
+
+    #[test]
+    fn encode_binary_overflow() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = encode_binary(128, 1, true, Endianness::Big, &mut buffer);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+        let output = encode_binary(-1, 2, false, Endianness::Big, &mut buffer);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+        let output = encode_binary(65536, 2, false, Endianness::Little, &mut buffer);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn encode_binary_invalid_length() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = encode_binary(1, 3, true, Endianness::Big, &mut buffer);
+        assert_eq!(Err(PadderError::InvalidLength(3)), output);
+    }
+
+    #[test]
+    fn decode_binary_sign_extension() {
+        assert_eq!(
+            Ok(-2),
+            decode_binary(&[0xFE, 0xFF], true, Endianness::Little)
+        );
+        assert_eq!(
+            Ok(65534),
+            decode_binary(&[0xFF, 0xFE], false, Endianness::Big)
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let values: [i128; 6] = [0, 1, -1, 127, -128, 1_000_000];
+        for endianness in [Endianness::Big, Endianness::Little] {
+            for length in [4, 8] {
+                for value in values {
+                    let mut buffer: Vec<u8> = Vec::new();
+                    encode_binary(value, length, true, endianness, &mut buffer).unwrap();
+                    assert_eq!(length, buffer.len());
+                    assert_eq!(Ok(value), decode_binary(&buffer, true, endianness));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn binary_and_text_in_one_record() {
+        let mut buffer: Vec<u8> = Vec::with_capacity(10);
+        pad_and_push_to_buffer(
+            "id".as_bytes(),
+            4,
+            Alignment::Right,
+            Symbol::Zero,
+            &mut buffer,
+        );
+        encode_binary(513, 2, false, Endianness::Big, &mut buffer).unwrap();
+        pad_and_push_to_buffer(
+            "ok".as_bytes(),
+            4,
+            Alignment::Left,
+            Symbol::Whitespace,
+            &mut buffer,
+        );
+        assert_eq!(b"00id\x02\x01ok  ", buffer.as_slice());
+    }
+}
@@ -0,0 +1,415 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Mapping of booleans and enums to short codes in fixed-width fields.
+//!
+//! Booleans are mapped with one of the conventions in [`BoolCodes`], while enums and
+//! other values are mapped with a user-provided [`CodeTable`]. In both cases a missing
+//! value is written as a field consisting only of the fill [`Symbol`], and the codes are
+//! padded with the existing [`Source`](crate::Source) implementation for `&[u8]`.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(3);
+//! BoolCodes::YesNo.write(Some(true), 1, Alignment::Left, Symbol::Whitespace, &mut buffer).unwrap();
+//! BoolCodes::YesNo.write(None, 1, Alignment::Left, Symbol::Whitespace, &mut buffer).unwrap();
+//! BoolCodes::OneZero.write(Some(false), 1, Alignment::Left, Symbol::Whitespace, &mut buffer).unwrap();
+//! assert_eq!(b"Y 0", buffer.as_slice());
+//!
+//! let parsed = BoolCodes::YesNo.parse(&buffer[..1], Alignment::Left, Symbol::Whitespace);
+//! assert_eq!(Ok(Some(true)), parsed);
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use crate::{pad_and_push_to_buffer, Alignment, PadderError, Symbol};
+
+/// Check whether the field is the code padded to the width of the field.
+fn is_padded_code(field: &[u8], code: &[u8], mode: Alignment, symbol: Symbol) -> bool {
+    if field.len() < code.len() {
+        return false;
+    }
+
+    let fill: u8 = symbol.into();
+    let (lpad, rpad) = mode.left_right_padding(field.len() - code.len());
+    field[..lpad].iter().all(|&b| b == fill)
+        && &field[lpad..(lpad + code.len())] == code
+        && field[(field.len() - rpad)..].iter().all(|&b| b == fill)
+}
+
+/// Pad the code to the target width and push it to the buffer, failing if the code is
+/// wider than the field since it could not be parsed back.
+fn push_code(
+    code: &str,
+    width: usize,
+    mode: Alignment,
+    symbol: Symbol,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    if code.len() > width {
+        return Err(PadderError::Overflow {
+            width,
+            required: code.len(),
+        });
+    }
+    pad_and_push_to_buffer(code.as_bytes(), width, mode, symbol, buffer);
+    Ok(())
+}
+
+/// Check whether the field consists only of the fill symbol.
+fn is_blank(field: &[u8], symbol: Symbol) -> bool {
+    let fill: u8 = symbol.into();
+    field.iter().all(|&b| b == fill)
+}
+
+/// Exhaustive enum for the conventions of encoding booleans as codes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum BoolCodes {
+    /// `Y` for true and `N` for false.
+    #[default]
+    YesNo,
+    /// `1` for true and `0` for false.
+    OneZero,
+    /// `T` for true and `F` for false.
+    TrueFalse,
+}
+
+impl BoolCodes {
+    /// Get the code for the boolean value.
+    pub fn code(&self, value: bool) -> &'static str {
+        match (self, value) {
+            (Self::YesNo, true) => "Y",
+            (Self::YesNo, false) => "N",
+            (Self::OneZero, true) => "1",
+            (Self::OneZero, false) => "0",
+            (Self::TrueFalse, true) => "T",
+            (Self::TrueFalse, false) => "F",
+        }
+    }
+
+    /// Pad the code of the value, or only the fill symbol if the value is missing, to
+    /// the target width and push it to the buffer.
+    ///
+    /// Returns an error if the code is wider than the target width.
+    pub fn write(
+        &self,
+        value: Option<bool>,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        let code: &str = value.map_or("", |v| self.code(v));
+        push_code(code, width, mode, symbol, buffer)
+    }
+
+    /// Parse a padded field into its boolean value, or [`None`] if the field consists
+    /// only of the fill symbol.
+    pub fn parse(
+        &self,
+        field: &[u8],
+        mode: Alignment,
+        symbol: Symbol,
+    ) -> Result<Option<bool>, PadderError> {
+        if is_padded_code(field, self.code(true).as_bytes(), mode, symbol) {
+            Ok(Some(true))
+        } else if is_padded_code(field, self.code(false).as_bytes(), mode, symbol) {
+            Ok(Some(false))
+        } else if is_blank(field, symbol) {
+            Ok(None)
+        } else {
+            Err(PadderError::UnknownCode(
+                String::from_utf8_lossy(field).into_owned(),
+            ))
+        }
+    }
+}
+
+/// A user-provided mapping between values and their codes.
+///
+/// ```
+/// use padder::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Status {
+///     Active,
+///     Closed,
+/// }
+///
+/// let table = CodeTable::new()
+///     .with(Status::Active, "ACT")
+///     .with(Status::Closed, "CL");
+///
+/// let mut buffer: Vec<u8> = Vec::with_capacity(4);
+/// table.write(Some(&Status::Closed), 4, Alignment::Left, Symbol::Whitespace, &mut buffer).unwrap();
+/// assert_eq!(b"CL  ", buffer.as_slice());
+///
+/// let parsed = table.parse(&buffer, Alignment::Left, Symbol::Whitespace);
+/// assert_eq!(Ok(Some(&Status::Closed)), parsed);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeTable<T> {
+    entries: Vec<(T, String)>,
+}
+
+impl<T> Default for CodeTable<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T: PartialEq> CodeTable<T> {
+    /// Create a new empty [`CodeTable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a mapping between the value and the code to the table.
+    pub fn with(mut self, value: T, code: &str) -> Self {
+        self.entries.push((value, code.to_string()));
+        self
+    }
+
+    /// Get the code of the value, if it exists in the table.
+    pub fn code(&self, value: &T) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(v, _)| v == value)
+            .map(|(_, code)| code.as_str())
+    }
+
+    /// Get the value of the code, if it exists in the table.
+    pub fn value(&self, code: &str) -> Option<&T> {
+        self.entries
+            .iter()
+            .find(|(_, c)| c == code)
+            .map(|(value, _)| value)
+    }
+
+    /// Pad the code of the value, or only the fill symbol if the value is missing, to
+    /// the target width and push it to the buffer.
+    ///
+    /// Returns an error if the value does not exist in the table, or if its code is wider
+    /// than the target width.
+    pub fn write(
+        &self,
+        value: Option<&T>,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError>
+    where
+        T: fmt::Debug,
+    {
+        let code: &str = match value {
+            Some(value) => self
+                .code(value)
+                .ok_or_else(|| PadderError::UnknownCode(format!("{:?}", value)))?,
+            None => "",
+        };
+        push_code(code, width, mode, symbol, buffer)
+    }
+
+    /// Parse a padded field into its value, or [`None`] if the field consists only of
+    /// the fill symbol.
+    pub fn parse(
+        &self,
+        field: &[u8],
+        mode: Alignment,
+        symbol: Symbol,
+    ) -> Result<Option<&T>, PadderError> {
+        if let Some((value, _)) = self
+            .entries
+            .iter()
+            .find(|(_, code)| is_padded_code(field, code.as_bytes(), mode, symbol))
+        {
+            Ok(Some(value))
+        } else if is_blank(field, symbol) {
+            Ok(None)
+        } else {
+            Err(PadderError::UnknownCode(
+                String::from_utf8_lossy(field).into_owned(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Currency {
+        Sek,
+        Eur,
+        Usd,
+    }
+
+    fn currencies() -> CodeTable<Currency> {
+        CodeTable::new()
+            .with(Currency::Sek, "752")
+            .with(Currency::Eur, "978")
+            .with(Currency::Usd, "840")
+    }
+
+    #[test]
+    fn bool_codes() {
+        assert_eq!("Y", BoolCodes::YesNo.code(true));
+        assert_eq!("0", BoolCodes::OneZero.code(false));
+        assert_eq!("T", BoolCodes::TrueFalse.code(true));
+        assert_eq!(BoolCodes::YesNo, BoolCodes::default());
+    }
+
+    #[test]
+    fn bool_write_padded() {
+        let mut buffer: Vec<u8> = Vec::new();
+        BoolCodes::TrueFalse
+            .write(
+                Some(false),
+                3,
+                Alignment::Right,
+                Symbol::Hyphen,
+                &mut buffer,
+            )
+            .unwrap();
+        BoolCodes::TrueFalse
+            .write(None, 3, Alignment::Right, Symbol::Hyphen, &mut buffer)
+            .unwrap();
+        assert_eq!(b"--F---", buffer.as_slice());
+
+        let output =
+            BoolCodes::YesNo.write(Some(true), 0, Alignment::Left, Symbol::Dot, &mut buffer);
+        assert_eq!(
+            Err(PadderError::Overflow {
+                width: 0,
+                required: 1,
+            }),
+            output
+        );
+    }
+
+    #[test]
+    fn bool_parse_zero_fill() {
+        let output = BoolCodes::OneZero.parse(b"0", Alignment::Right, Symbol::Zero);
+        assert_eq!(Ok(Some(false)), output);
+        let output = BoolCodes::OneZero.parse(b"01", Alignment::Right, Symbol::Zero);
+        assert_eq!(Ok(Some(true)), output);
+    }
+
+    #[test]
+    fn bool_parse_blank() {
+        let output = BoolCodes::YesNo.parse(b"   ", Alignment::Center, Symbol::Whitespace);
+        assert_eq!(Ok(None), output);
+    }
+
+    #[test]
+    fn bool_parse_unknown() {
+        let output = BoolCodes::YesNo.parse(b"X", Alignment::Left, Symbol::Whitespace);
+        assert_eq!(Err(PadderError::UnknownCode("X".to_string())), output);
+        let output = BoolCodes::YesNo.parse(b"Y ", Alignment::Right, Symbol::Whitespace);
+        assert_eq!(Err(PadderError::UnknownCode("Y ".to_string())), output);
+    }
+
+    #[test]
+    fn table_lookup() {
+        let table = currencies();
+        assert_eq!(Some("978"), table.code(&Currency::Eur));
+        assert_eq!(Some(&Currency::Usd), table.value("840"));
+        assert_eq!(None, table.value("999"));
+    }
+
+    #[test]
+    fn table_write_unknown() {
+        let table = CodeTable::new().with(Currency::Sek, "752");
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = table.write(
+            Some(&Currency::Eur),
+            3,
+            Alignment::Left,
+            Symbol::Whitespace,
+            &mut buffer,
+        );
+        assert_eq!(Err(PadderError::UnknownCode("Eur".to_string())), output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn table_write_overflow() {
+        let table = currencies();
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = table.write(
+            Some(&Currency::Usd),
+            2,
+            Alignment::Left,
+            Symbol::Whitespace,
+            &mut buffer,
+        );
+        assert_eq!(
+            Err(PadderError::Overflow {
+                width: 2,
+                required: 3,
+            }),
+            output
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn table_round_trip() {
+        let table = currencies();
+        let values = [Some(Currency::Sek), None, Some(Currency::Usd)];
+        let mut buffer: Vec<u8> = Vec::new();
+        for value in values.iter() {
+            table
+                .write(
+                    value.as_ref(),
+                    5,
+                    Alignment::Center,
+                    Symbol::Dot,
+                    &mut buffer,
+                )
+                .unwrap();
+        }
+        assert_eq!(b".752.......840.", buffer.as_slice());
+
+        for (idx, value) in values.iter().enumerate() {
+            let field: &[u8] = &buffer[(idx * 5)..((idx + 1) * 5)];
+            let parsed = table.parse(field, Alignment::Center, Symbol::Dot);
+            assert_eq!(Ok(value.as_ref()), parsed);
+        }
+    }
+}
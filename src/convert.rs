@@ -0,0 +1,454 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Conversion between CSV and fixed-width files.
+//!
+//! [`csv_to_fixed_width`] reads delimited rows and writes each of them as a record with
+//! a [`FixedWidthWriter`], mapping the columns to the fields of its layout by header
+//! name, by position, or to constant values. The values are padded exactly like any
+//! other values written with a [`Layout`].
+//!
+//! In the other direction, [`fixed_width_to_csv`] writes the rows read by a
+//! [`FixedWidthReader`] with the padding stripped and the numbers decoded, including
+//! their implied decimals.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("type", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("name", 6, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//! let options = CsvOptions::default()
+//!     .with_delimiter(b';')
+//!     .with_column("type", Column::Constant(Value::from("02")))
+//!     .with_column("amount", Column::Name("AMT".to_string()));
+//!
+//! let input = "name;AMT\nacme;12.5\n\"b;c\";3\n";
+//! let mut writer = FixedWidthWriter::new(Vec::new(), layout);
+//! let count: u64 = csv_to_fixed_width(input.as_bytes(), &mut writer, &options).unwrap();
+//! assert_eq!(2, count);
+//! assert_eq!(b"02acme  001250\n02b;c   000300\n", writer.finish().unwrap().as_slice());
+//! ```
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("name", 6, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//! let input = "b,c   000300\n";
+//!
+//! let reader = FixedWidthReader::new(input.as_bytes(), layout);
+//! let mut output: Vec<u8> = Vec::new();
+//! fixed_width_to_csv(reader, &mut output, &CsvOptions::default()).unwrap();
+//! assert_eq!(b"name,amount\n\"b,c\",3.00\n", output.as_slice());
+//! ```
+//!
+
+use std::io;
+
+use crate::layout::Layout;
+use crate::reader::{FixedWidthReader, Row};
+use crate::value::Value;
+use crate::writer::FixedWidthWriter;
+use crate::PadderError;
+
+/// Exhaustive enum for the sources of the value of a field when converting from CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    /// The column with the given header name.
+    Name(String),
+    /// The column at the given position, starting from zero.
+    Index(usize),
+    /// The same value for every row.
+    Constant(Value<'static>),
+}
+
+/// Options for reading and writing delimited files.
+///
+/// When reading, fields without a column mapping are taken from the column with the
+/// same header name, or from the column at the same position if the file has no header
+/// row. Fields whose column does not exist are written as missing values. When writing,
+/// the column mapping is not used and every field is written in the order of the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    delimiter: u8,
+    quote: u8,
+    has_headers: bool,
+    columns: Vec<(String, Column)>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            has_headers: true,
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Set the delimiter between columns, which is a comma by default.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character of quoted columns, which is a double quote by default.
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Set whether the first row is a header row with the names of the columns, which
+    /// it is by default.
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Get the default options for tab-separated files.
+    pub fn tsv() -> Self {
+        Self::default().with_delimiter(b'\t')
+    }
+
+    /// Set the column that the value of the field with the given name is taken from.
+    pub fn with_column(mut self, field: &str, column: Column) -> Self {
+        self.columns.retain(|(f, _)| f != field);
+        self.columns.push((field.to_string(), column));
+        self
+    }
+}
+
+/// Convert a CSV error into a [`PadderError`], keeping the position of the row if known.
+fn csv_error(error: csv::Error) -> PadderError {
+    let position: Option<csv::Position> = error.position().cloned();
+    let error: PadderError = match error.into_kind() {
+        csv::ErrorKind::Io(error) => PadderError::from(error),
+        csv::ErrorKind::Utf8 { err, .. } => PadderError::InvalidValue(err.to_string()),
+        csv::ErrorKind::UnequalLengths {
+            expected_len, len, ..
+        } => PadderError::FieldCount {
+            expected: expected_len as usize,
+            found: len as usize,
+        },
+        kind => PadderError::InvalidValue(format!("{:?}", kind)),
+    };
+    match position {
+        Some(position) => PadderError::Record {
+            line: position.line(),
+            offset: position.byte(),
+            error: Box::new(error),
+        },
+        None => error,
+    }
+}
+
+/// Exhaustive enum for where the value of each field of the layout comes from.
+enum Origin {
+    Index(usize),
+    Constant(Value<'static>),
+    Missing,
+}
+
+/// Resolve the column of each field of the layout against the header row.
+fn resolve(
+    layout: &Layout,
+    options: &CsvOptions,
+    headers: Option<&csv::StringRecord>,
+) -> Result<Vec<Origin>, PadderError> {
+    let position = |name: &str| headers.and_then(|h| h.iter().position(|c| c == name));
+
+    let mut origins: Vec<Origin> = Vec::with_capacity(layout.fields().len());
+    for (index, field) in layout.fields().iter().enumerate() {
+        let column: Option<&Column> = options
+            .columns
+            .iter()
+            .find(|(f, _)| *f == field.name)
+            .map(|(_, c)| c);
+
+        origins.push(match column {
+            Some(Column::Name(name)) => {
+                let index: usize = position(name).ok_or_else(|| PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(PadderError::InvalidLayout(format!(
+                        "no column named '{}'",
+                        name
+                    ))),
+                })?;
+                Origin::Index(index)
+            }
+            Some(Column::Index(index)) => Origin::Index(*index),
+            Some(Column::Constant(value)) => Origin::Constant(value.clone()),
+            None if headers.is_some() => {
+                position(&field.name).map_or(Origin::Missing, Origin::Index)
+            }
+            None => Origin::Index(index),
+        });
+    }
+    Ok(origins)
+}
+
+/// Read delimited rows from the input and write each of them as a fixed-width record
+/// with the writer, returning the number of rows converted.
+///
+/// Errors in a row carry the line number and byte offset of the row in the input.
+pub fn csv_to_fixed_width<R: io::Read, W: io::Write>(
+    input: R,
+    writer: &mut FixedWidthWriter<W>,
+    options: &CsvOptions,
+) -> Result<u64, PadderError> {
+    let mut reader: csv::Reader<R> = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .has_headers(options.has_headers)
+        .flexible(true)
+        .from_reader(input);
+
+    let headers: Option<csv::StringRecord> = match options.has_headers {
+        true => Some(reader.headers().map_err(csv_error)?.clone()),
+        false => None,
+    };
+    let origins: Vec<Origin> = resolve(writer.layout(), options, headers.as_ref())?;
+
+    let mut count: u64 = 0;
+    let mut row = csv::StringRecord::new();
+    while reader.read_record(&mut row).map_err(csv_error)? {
+        let values: Vec<Value> = origins
+            .iter()
+            .map(|origin| match origin {
+                Origin::Index(index) => row.get(*index).map_or(Value::Null, Value::from),
+                Origin::Constant(value) => value.clone(),
+                Origin::Missing => Value::Null,
+            })
+            .collect();
+
+        writer.write_row(&values).map_err(|error| {
+            let position: Option<&csv::Position> = row.position();
+            PadderError::Record {
+                line: position.map_or(0, |p| p.line()),
+                offset: position.map_or(0, |p| p.byte()),
+                error: Box::new(error),
+            }
+        })?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Write the rows of the reader as delimited rows to the output, returning the number
+/// of rows written.
+///
+/// If the options have headers, the names of the fields of the layout of the first row
+/// are written as the header row. Missing values are written as empty columns.
+pub fn fixed_width_to_csv<R: io::BufRead, W: io::Write>(
+    reader: FixedWidthReader<R>,
+    output: W,
+    options: &CsvOptions,
+) -> Result<u64, PadderError> {
+    let mut writer: csv::Writer<W> = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .flexible(true)
+        .from_writer(output);
+
+    let mut count: u64 = 0;
+    let mut columns: Vec<String> = Vec::new();
+    for row in reader {
+        let row: Row = row?;
+        if count == 0 && options.has_headers {
+            let names = row.layout().fields().iter().map(|f| f.name.as_str());
+            writer.write_record(names).map_err(csv_error)?;
+        }
+
+        columns.clear();
+        columns.extend(row.values().iter().map(|value| value.to_string()));
+        writer.write_record(&columns).map_err(csv_error)?;
+        count += 1;
+    }
+
+    writer.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::writer::{Terminator, TrailerField};
+    use crate::{Alignment, Symbol};
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new("id", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                5,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new("note", 3, Alignment::Left, Symbol::Hyphen, FieldKind::Text),
+        ])
+    }
+
+    fn convert(input: &str, options: &CsvOptions) -> Result<String, PadderError> {
+        let mut writer = FixedWidthWriter::new(Vec::new(), layout());
+        csv_to_fixed_width(input.as_bytes(), &mut writer, options)?;
+        Ok(String::from_utf8(writer.finish()?).unwrap())
+    }
+
+    #[test]
+    fn convert_by_header_names() {
+        let input = "name,extra,id\n\"a, b\",x,7\nc,y,12\n";
+        let output = convert(input, &CsvOptions::default()).unwrap();
+        assert_eq!("007a, b ---\n012c    ---\n", output);
+    }
+
+    #[test]
+    fn convert_without_headers() {
+        let options = CsvOptions::default()
+            .with_headers(false)
+            .with_delimiter(b'\t')
+            .with_quote(b'\'')
+            .with_column("note", Column::Index(3));
+        let output = convert("1\t'x\ty'\tskip\tok\n2\tz\n", &options).unwrap();
+        assert_eq!("001x\ty  ok-\n002z    ---\n", output);
+    }
+
+    #[test]
+    fn convert_with_trailer() {
+        let trailer = Layout::new(vec![Field::new(
+            "count",
+            4,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )]);
+        let mut writer = FixedWidthWriter::new(Vec::new(), layout())
+            .with_terminator(Terminator::CrLf)
+            .with_trailer(trailer, vec![TrailerField::RecordCount])
+            .unwrap();
+        let options =
+            CsvOptions::default().with_column("name", Column::Constant(Value::from("const")));
+        let count = csv_to_fixed_width("id\n1\n2\n".as_bytes(), &mut writer, &options).unwrap();
+        assert_eq!(2, count);
+        assert_eq!(
+            b"001const---\r\n002const---\r\n0002\r\n",
+            writer.finish().unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn convert_errors() {
+        let options =
+            CsvOptions::default().with_column("name", Column::Name("missing".to_string()));
+        let output = convert("id,name\n1,a\n", &options);
+        assert!(matches!(output, Err(PadderError::Field { ref name, .. }) if name == "name"));
+
+        let output = convert("id,name\n1,a\n1000,b\n", &CsvOptions::default());
+        assert!(matches!(
+            output,
+            Err(PadderError::Record { line: 3, offset: 12, ref error }) if matches!(**error, PadderError::Field { .. })
+        ));
+    }
+
+    #[test]
+    fn fixed_width_to_delimited() {
+        let layout = Layout::new(vec![
+            Field::new("id", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                5,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "rate",
+                5,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(3),
+            Field::new("note", 3, Alignment::Left, Symbol::Hyphen, FieldKind::Text),
+        ]);
+        let input = "007a\"b  01250x--\n012c\td  00005---\n";
+
+        let reader = FixedWidthReader::new(input.as_bytes(), layout.clone());
+        let mut output: Vec<u8> = Vec::new();
+        assert_eq!(
+            2,
+            fixed_width_to_csv(reader, &mut output, &CsvOptions::default()).unwrap()
+        );
+        assert_eq!(
+            "id,name,rate,note\n7,\"a\"\"b\",1.250,x\n12,c\td,0.005,\n",
+            String::from_utf8(output).unwrap()
+        );
+
+        let reader = FixedWidthReader::new(input.as_bytes(), layout);
+        let mut output: Vec<u8> = Vec::new();
+        let options = CsvOptions::tsv().with_headers(false);
+        assert_eq!(
+            2,
+            fixed_width_to_csv(reader, &mut output, &options).unwrap()
+        );
+        assert_eq!(
+            "7\t\"a\"\"b\"\t1.250\tx\n12\t\"c\td\"\t0.005\t\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn fixed_width_to_delimited_errors() {
+        let layout = Layout::new(vec![Field::new(
+            "id",
+            3,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )]);
+        let reader = FixedWidthReader::new("001\n0x2\n".as_bytes(), layout);
+        let output = fixed_width_to_csv(reader, Vec::new(), &CsvOptions::default());
+        assert!(matches!(
+            output,
+            Err(PadderError::Record {
+                line: 2,
+                offset: 4,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,919 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! COBOL copybook import into record layouts.
+//!
+//! A subset of the copybook syntax is supported, enough to describe the flat records
+//! exchanged with mainframe systems: level numbers, `PIC X(n)`, `PIC A(n)`,
+//! `PIC S9(n)V9(m)`, numeric edited pictures, `USAGE` with `DISPLAY`, `COMP`, `COMP-3`,
+//! `COMP-4`, `COMP-5` and `BINARY`, `OCCURS`, `REDEFINES`, `FILLER`, `VALUE` and
+//! `JUSTIFIED`. Condition names (level 88) and `RENAMES` (level 66) are skipped.
+//!
+//! Every elementary item becomes one [`Field`] of the layout:
+//!
+//! | Picture and usage       | Kind                       | Alignment | Symbol       |
+//! |-------------------------|----------------------------|-----------|--------------|
+//! | `X`, `A`                | [`FieldKind::Text`]        | Left      | `Whitespace` |
+//! | `9`                     | [`FieldKind::Zoned`]       | Right     | `Zero`       |
+//! | `S9`                    | [`FieldKind::Signed`]      | Right     | `Zero`       |
+//! | `9` with `COMP-3`       | [`FieldKind::Packed`]      | Right     | `Zero`       |
+//! | `9` with `COMP`         | [`FieldKind::Binary`]      | Right     | `Zero`       |
+//! | numeric edited          | [`FieldKind::Text`]        | Right     | `Whitespace` |
+//!
+//! The zoned kinds take the [`Encoding`] given to the parser. Text is not translated,
+//! so under [`Encoding::Ebcdic`] the `X` and `A` items keep the raw bytes of the record.
+//!
+//! Items that `OCCURS` are repeated with their subscripts appended to the name, like
+//! `AMOUNT(1)` or `AMOUNT(2,3)` for nested tables. Items that `REDEFINES` another item
+//! overlay its storage and are skipped, as are any later level 01 records, which
+//! implicitly redefine the first one.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let copybook = "
+//!        01  CUSTOMER-RECORD.
+//!            05  CUST-ID         PIC 9(6).
+//!            05  CUST-NAME       PIC X(10).
+//!            05  BALANCE         PIC S9(5)V99 COMP-3.
+//!            05  FILLER          PIC X(2).
+//! ";
+//! let layout: Layout = parse_copybook(copybook, Encoding::Ebcdic).unwrap();
+//! assert_eq!(22, layout.width());
+//!
+//! let balance: &Field = layout.field("BALANCE").unwrap();
+//! assert_eq!(FieldKind::Packed { signed: true }, balance.kind);
+//! assert_eq!((4, 2), (balance.width, balance.scale));
+//! ```
+//!
+
+use crate::binary::Endianness;
+use crate::layout::{Field, FieldKind, Layout};
+use crate::packed::packed_length;
+use crate::zoned::Encoding;
+use crate::{Alignment, PadderError, Symbol};
+
+/// The most fields a copybook can expand to, counting every occurrence of its tables.
+const MAX_FIELDS: usize = 100_000;
+
+/// Exhaustive enum for the supported storage formats of copybook items.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Usage {
+    Display,
+    Packed,
+    Binary,
+}
+
+/// Exhaustive enum for the categories of pictures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Category {
+    Alphanumeric,
+    Numeric,
+    Edited,
+}
+
+/// A parsed picture string, like `S9(5)V99`.
+#[derive(Debug, Clone, PartialEq)]
+struct Picture {
+    category: Category,
+    signed: bool,
+    digits: usize,
+    scale: u32,
+    /// The number of characters the picture occupies when displayed.
+    length: usize,
+}
+
+/// One data description entry of the copybook, ending with a period.
+#[derive(Debug, Default)]
+struct Entry {
+    line: usize,
+    level: u8,
+    name: String,
+    picture: Option<Picture>,
+    usage: Option<Usage>,
+    occurs: Option<usize>,
+    redefines: bool,
+    justified: bool,
+}
+
+/// An entry together with its subordinate entries.
+#[derive(Debug)]
+struct Item {
+    entry: Entry,
+    children: Vec<Item>,
+}
+
+/// Create the error for an invalid copybook at the given line.
+fn invalid(line: usize, message: &str) -> PadderError {
+    PadderError::InvalidLayout(format!("copybook line {}: {}", line, message))
+}
+
+/// Get the code area of a copybook line, or [`None`] if the line is a comment.
+///
+/// Lines in the fixed reference format, where the first six columns hold a sequence
+/// number or are blank, have their sequence, indicator and identification areas removed.
+/// Columns are counted in characters, so that text with non-ASCII characters is never
+/// sliced in the middle of a character.
+fn code_area(line: &str) -> Option<&str> {
+    // The first six columns are ASCII whenever they are a sequence area, so the indicator
+    // starts at byte 6.
+    let indicator: Option<char> = line.get(6..).and_then(|rest| rest.chars().next());
+    let fixed: bool = line.get(..6).is_some_and(|area| {
+        area.bytes().all(|b| b.is_ascii_digit())
+            || (area.bytes().all(|b| b == b' ')
+                && matches!(indicator, None | Some(' ' | '*' | '/' | '-')))
+    });
+
+    let code: &str = if fixed {
+        match indicator {
+            Some('*' | '/') => return None,
+            Some(indicator) => {
+                let area: &str = &line[6 + indicator.len_utf8()..];
+                let end: usize = area.char_indices().nth(72 - 7).map_or(area.len(), |c| c.0);
+                &area[..end]
+            }
+            None => "",
+        }
+    } else {
+        line
+    };
+
+    let code: &str = code.split("*>").next().unwrap_or(code);
+    if code.trim_start().starts_with('*') {
+        return None;
+    }
+    Some(code)
+}
+
+/// Split the copybook into its period-terminated entries, each a list of tokens together
+/// with the line it starts on. Quoted literals are kept as single tokens.
+fn statements(source: &str) -> Vec<(usize, Vec<String>)> {
+    let mut statements: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut tokens: Vec<String> = Vec::new();
+    let mut start: usize = 0;
+
+    for (index, line) in source.lines().enumerate() {
+        let Some(code) = code_area(line) else {
+            continue;
+        };
+
+        let mut chars = code.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+                continue;
+            }
+            if tokens.is_empty() {
+                start = index + 1;
+            }
+
+            let mut token = String::new();
+            let mut terminated: bool = false;
+            if c == '\'' || c == '"' {
+                token.push(c);
+                chars.next();
+                for next in chars.by_ref() {
+                    token.push(next);
+                    if next == c {
+                        break;
+                    }
+                }
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    terminated = true;
+                }
+            } else {
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                }
+                if token.ends_with('.') {
+                    token.pop();
+                    terminated = true;
+                }
+            }
+
+            if !token.is_empty() {
+                tokens.push(token);
+            }
+            if terminated && !tokens.is_empty() {
+                statements.push((start, std::mem::take(&mut tokens)));
+            }
+        }
+    }
+
+    if !tokens.is_empty() {
+        statements.push((start, tokens));
+    }
+    statements
+}
+
+/// Parse a picture string like `S9(5)V99` or `X(10)`.
+fn parse_picture(picture: &str, line: usize) -> Result<Picture, PadderError> {
+    let error = || invalid(line, &format!("invalid picture '{}'", picture));
+
+    // Each symbol with its number of repeats, so that counts like X(1000000) are added
+    // up rather than expanded.
+    let mut runs: Vec<(char, usize)> = Vec::with_capacity(picture.len());
+    let mut chars = picture.chars().map(|c| c.to_ascii_uppercase()).peekable();
+    while let Some(c) = chars.next() {
+        if c == '(' {
+            let mut count = String::new();
+            for next in chars.by_ref() {
+                if next == ')' {
+                    break;
+                }
+                count.push(next);
+            }
+            let repeat: usize = count.parse().map_err(|_| error())?;
+            let Some((_, previous)) = runs.last_mut() else {
+                return Err(error());
+            };
+            *previous = previous
+                .checked_add(repeat.saturating_sub(1))
+                .ok_or_else(error)?;
+        } else {
+            runs.push((c, 1));
+        }
+    }
+
+    let mut output = Picture {
+        category: Category::Numeric,
+        signed: false,
+        digits: 0,
+        scale: 0,
+        length: 0,
+    };
+    let mut alphanumeric: bool = false;
+    let mut edited: bool = false;
+    let mut decimals: bool = false;
+
+    for (position, (symbol, count)) in runs.into_iter().enumerate() {
+        match symbol {
+            'S' if position == 0 && count == 1 => output.signed = true,
+            'V' if !decimals && count == 1 => decimals = true,
+            '9' => {
+                output.digits = output.digits.checked_add(count).ok_or_else(error)?;
+                output.length = output.length.checked_add(count).ok_or_else(error)?;
+                if decimals {
+                    let count: u32 = u32::try_from(count).map_err(|_| error())?;
+                    output.scale = output.scale.checked_add(count).ok_or_else(error)?;
+                }
+            }
+            'X' | 'A' => {
+                alphanumeric = true;
+                output.length = output.length.checked_add(count).ok_or_else(error)?;
+            }
+            'Z' | '*' | '.' | ',' | '+' | '-' | '$' | 'B' | '0' | '/' | 'C' | 'R' | 'D' => {
+                edited = true;
+                output.length = output.length.checked_add(count).ok_or_else(error)?;
+            }
+            _ => return Err(invalid(line, &format!("unsupported picture '{}'", picture))),
+        }
+    }
+
+    output.category = if alphanumeric {
+        Category::Alphanumeric
+    } else if edited {
+        Category::Edited
+    } else if output.digits > 0 {
+        Category::Numeric
+    } else {
+        return Err(error());
+    };
+    Ok(output)
+}
+
+/// Parse a usage keyword, or [`None`] if the word is not a usage.
+fn parse_usage(word: &str, line: usize) -> Result<Option<Usage>, PadderError> {
+    match word {
+        "DISPLAY" => Ok(Some(Usage::Display)),
+        "COMP-3" | "COMPUTATIONAL-3" | "PACKED-DECIMAL" => Ok(Some(Usage::Packed)),
+        "COMP" | "COMP-4" | "COMP-5" | "COMPUTATIONAL" | "COMPUTATIONAL-4" | "COMPUTATIONAL-5"
+        | "BINARY" => Ok(Some(Usage::Binary)),
+        "COMP-1" | "COMP-2" | "COMPUTATIONAL-1" | "COMPUTATIONAL-2" | "INDEX" | "POINTER" => {
+            Err(invalid(line, &format!("unsupported usage '{}'", word)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parse the tokens of one data description entry, or [`None`] if the entry is skipped.
+fn parse_entry(line: usize, tokens: &[String]) -> Result<Option<Entry>, PadderError> {
+    let words: Vec<String> = tokens
+        .iter()
+        .map(|t| {
+            if t.starts_with('\'') || t.starts_with('"') {
+                t.clone()
+            } else {
+                t.to_ascii_uppercase()
+            }
+        })
+        .collect();
+
+    let level: u8 = words[0].parse().map_err(|_| {
+        invalid(
+            line,
+            &format!("expected a level number, found '{}'", words[0]),
+        )
+    })?;
+    match level {
+        66 | 88 => return Ok(None),
+        1..=49 | 77 => {}
+        _ => return Err(invalid(line, &format!("invalid level number {}", level))),
+    }
+
+    let mut entry = Entry {
+        line,
+        level,
+        name: "FILLER".to_string(),
+        ..Default::default()
+    };
+
+    const CLAUSES: [&str; 12] = [
+        "PIC",
+        "PICTURE",
+        "USAGE",
+        "OCCURS",
+        "REDEFINES",
+        "VALUE",
+        "VALUES",
+        "JUST",
+        "JUSTIFIED",
+        "SYNC",
+        "SYNCHRONIZED",
+        "BLANK",
+    ];
+    let mut index: usize = 1;
+    if let Some(word) = words.get(index) {
+        if !CLAUSES.contains(&word.as_str()) && parse_usage(word, line)?.is_none() {
+            entry.name = tokens[index].clone();
+            index += 1;
+        }
+    }
+
+    let next = |index: &mut usize| -> Result<&str, PadderError> {
+        let word: &str = words
+            .get(*index)
+            .ok_or_else(|| invalid(line, "unexpected end of entry"))?;
+        *index += 1;
+        Ok(word)
+    };
+    let skip = |index: &mut usize, optional: &[&str]| {
+        if words
+            .get(*index)
+            .is_some_and(|w| optional.contains(&w.as_str()))
+        {
+            *index += 1;
+        }
+    };
+
+    while index < words.len() {
+        let word: &str = next(&mut index)?;
+        match word {
+            "PIC" | "PICTURE" => {
+                skip(&mut index, &["IS"]);
+                let picture: &str = next(&mut index)?;
+                entry.picture = Some(parse_picture(picture, line)?);
+            }
+            "USAGE" => {
+                skip(&mut index, &["IS"]);
+                let usage: &str = next(&mut index)?;
+                entry.usage = Some(
+                    parse_usage(usage, line)?
+                        .ok_or_else(|| invalid(line, &format!("unknown usage '{}'", usage)))?,
+                );
+            }
+            "OCCURS" => {
+                let count: &str = next(&mut index)?;
+                let count: usize = count
+                    .parse()
+                    .map_err(|_| invalid(line, &format!("invalid OCCURS count '{}'", count)))?;
+                if words.get(index).is_some_and(|w| w == "TO") {
+                    return Err(invalid(line, "variable length OCCURS is not supported"));
+                }
+                skip(&mut index, &["TIMES"]);
+                if words.get(index).is_some_and(|w| w == "INDEXED") {
+                    index += 1;
+                    skip(&mut index, &["BY"]);
+                    next(&mut index)?;
+                }
+                entry.occurs = Some(count);
+            }
+            "REDEFINES" => {
+                next(&mut index)?;
+                entry.redefines = true;
+            }
+            "VALUE" | "VALUES" => {
+                skip(&mut index, &["IS", "ARE"]);
+                next(&mut index)?;
+            }
+            "JUST" | "JUSTIFIED" => {
+                skip(&mut index, &["RIGHT"]);
+                entry.justified = true;
+            }
+            "SYNC" | "SYNCHRONIZED" => skip(&mut index, &["LEFT", "RIGHT"]),
+            "BLANK" => {
+                skip(&mut index, &["WHEN"]);
+                next(&mut index)?;
+            }
+            _ => match parse_usage(word, line)? {
+                Some(usage) => entry.usage = Some(usage),
+                None => {
+                    return Err(invalid(line, &format!("unsupported clause '{}'", word)));
+                }
+            },
+        }
+    }
+
+    Ok(Some(entry))
+}
+
+/// Arrange the entries into a hierarchy of items by their level numbers.
+fn hierarchy(entries: Vec<Entry>) -> Vec<Item> {
+    fn close(stack: &mut Vec<Item>, roots: &mut Vec<Item>) {
+        if let Some(item) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(item),
+                None => roots.push(item),
+            }
+        }
+    }
+
+    let mut roots: Vec<Item> = Vec::new();
+    let mut stack: Vec<Item> = Vec::new();
+    for entry in entries {
+        let level: u8 = if entry.level == 77 { 1 } else { entry.level };
+        while stack.last().is_some_and(|item| {
+            let top: u8 = if item.entry.level == 77 {
+                1
+            } else {
+                item.entry.level
+            };
+            top >= level
+        }) {
+            close(&mut stack, &mut roots);
+        }
+        stack.push(Item {
+            entry,
+            children: Vec::new(),
+        });
+    }
+    while !stack.is_empty() {
+        close(&mut stack, &mut roots);
+    }
+    roots
+}
+
+/// Create the field of an elementary item.
+fn field(
+    entry: &Entry,
+    name: &str,
+    usage: Usage,
+    encoding: Encoding,
+) -> Result<Field, PadderError> {
+    let picture: &Picture = entry
+        .picture
+        .as_ref()
+        .ok_or_else(|| invalid(entry.line, &format!("'{}' has no picture", entry.name)))?;
+
+    if usage != Usage::Display && picture.category != Category::Numeric {
+        return Err(invalid(
+            entry.line,
+            &format!(
+                "'{}' has a computational usage but no numeric picture",
+                entry.name
+            ),
+        ));
+    }
+
+    let field: Field = match (picture.category, usage) {
+        (Category::Alphanumeric, _) => {
+            let alignment: Alignment = if entry.justified {
+                Alignment::Right
+            } else {
+                Alignment::Left
+            };
+            Field::new(
+                name,
+                picture.length,
+                alignment,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            )
+        }
+        (Category::Edited, _) => Field::new(
+            name,
+            picture.length,
+            Alignment::Right,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        ),
+        (Category::Numeric, Usage::Display) => {
+            let kind: FieldKind = if picture.signed {
+                FieldKind::Signed(encoding)
+            } else {
+                FieldKind::Zoned(encoding)
+            };
+            Field::new(name, picture.digits, Alignment::Right, Symbol::Zero, kind)
+                .with_scale(picture.scale)
+        }
+        (Category::Numeric, Usage::Packed) => Field::new(
+            name,
+            packed_length(picture.digits),
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Packed {
+                signed: picture.signed,
+            },
+        )
+        .with_scale(picture.scale),
+        (Category::Numeric, Usage::Binary) => {
+            let width: usize = match picture.digits {
+                1..=4 => 2,
+                5..=9 => 4,
+                10..=18 => 8,
+                _ => {
+                    return Err(invalid(
+                        entry.line,
+                        &format!("'{}' has too many digits for a binary field", entry.name),
+                    ))
+                }
+            };
+            Field::new(
+                name,
+                width,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Binary {
+                    signed: picture.signed,
+                    endianness: Endianness::Big,
+                },
+            )
+            .with_scale(picture.scale)
+        }
+    };
+    Ok(field)
+}
+
+/// Push the fields of the item and its subordinate items, repeating those that occur
+/// more than once, where the subscripts are the subscripts of the enclosing tables.
+fn flatten(
+    item: &Item,
+    usage: Usage,
+    encoding: Encoding,
+    subscripts: &[usize],
+    fields: &mut Vec<Field>,
+) -> Result<(), PadderError> {
+    let entry: &Entry = &item.entry;
+    if entry.redefines {
+        return Ok(());
+    }
+
+    if !item.children.is_empty() && entry.picture.is_some() {
+        return Err(invalid(
+            entry.line,
+            &format!("group item '{}' has a picture", entry.name),
+        ));
+    }
+
+    let usage: Usage = entry.usage.unwrap_or(usage);
+    // Items that do not occur more than once are visited once without a subscript.
+    let occurrences = match entry.occurs {
+        Some(count) => 1..=count,
+        None => 0..=0,
+    };
+
+    for occurrence in occurrences {
+        let mut subscripts: Vec<usize> = subscripts.to_vec();
+        subscripts.extend((occurrence > 0).then_some(occurrence));
+
+        if item.children.is_empty() {
+            if fields.len() == MAX_FIELDS {
+                return Err(invalid(
+                    entry.line,
+                    &format!("the record has more than {} fields", MAX_FIELDS),
+                ));
+            }
+            let name: String = if subscripts.is_empty() {
+                entry.name.clone()
+            } else {
+                let subscripts: Vec<String> = subscripts.iter().map(|s| s.to_string()).collect();
+                format!("{}({})", entry.name, subscripts.join(","))
+            };
+            fields.push(field(entry, &name, usage, encoding)?);
+        } else {
+            for child in &item.children {
+                flatten(child, usage, encoding, &subscripts, fields)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a COBOL copybook into a record layout.
+///
+/// Signed display numbers (`S9`) are zoned decimals with an overpunched sign in the
+/// given character encoding, while packed and binary numbers are encoding independent.
+/// If the copybook holds several level 01 records, only the first one is used. The
+/// layout is validated before it is returned.
+pub fn parse_copybook(source: &str, encoding: Encoding) -> Result<Layout, PadderError> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for (line, tokens) in statements(source) {
+        if let Some(entry) = parse_entry(line, &tokens)? {
+            entries.push(entry);
+        }
+    }
+
+    let roots: Vec<Item> = hierarchy(entries);
+    let records: &[Item] = match roots.first() {
+        Some(first) if first.entry.level == 1 => std::slice::from_ref(first),
+        _ => &roots,
+    };
+
+    let mut fields: Vec<Field> = Vec::new();
+    for record in records {
+        flatten(record, Usage::Display, encoding, &[], &mut fields)?;
+    }
+    fields
+        .iter()
+        .try_fold(0usize, |width, field| width.checked_add(field.width))
+        .ok_or_else(|| PadderError::InvalidLayout("the record is too wide".to_string()))?;
+
+    let layout = Layout::new(fields);
+    layout.validate()?;
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(layout: &Layout) -> Vec<(&str, usize, u32)> {
+        layout
+            .fields()
+            .iter()
+            .map(|f| (f.name.as_str(), f.width, f.scale))
+            .collect()
+    }
+
+    #[test]
+    fn parse_pictures() {
+        let picture = parse_picture("S9(5)V99", 1).unwrap();
+        assert_eq!(
+            Picture {
+                category: Category::Numeric,
+                signed: true,
+                digits: 7,
+                scale: 2,
+                length: 7,
+            },
+            picture
+        );
+        assert_eq!(12, parse_picture("x(12)", 1).unwrap().length);
+        let edited = parse_picture("ZZ,ZZ9.99-", 1).unwrap();
+        assert_eq!((Category::Edited, 10), (edited.category, edited.length));
+        assert!(parse_picture("9(x)", 1).is_err());
+        assert!(parse_picture("9P", 1).is_err());
+        assert!(parse_picture("V", 1).is_err());
+        assert!(parse_picture("SS9", 1).is_err());
+        assert_eq!(100000000, parse_picture("X(100000000)", 1).unwrap().length);
+        assert!(parse_picture("X(18446744073709551615)X(2)", 1).is_err());
+    }
+
+    #[test]
+    fn copybook_fields() {
+        let copybook = "
+       01  ACCOUNT.
+           05  ACCOUNT-ID          PIC 9(8).
+           05  ACCOUNT-NAME        PIC X(20).
+           05  BALANCE             PIC S9(7)V99.
+           05  RATE                PIC 9V9(4) COMP-3.
+           05  BRANCH              PIC S9(4) USAGE IS COMP.
+           05  OPENED              PIC 9(8) VALUE ZEROS.
+           05  CODE-R              PIC X(3) JUSTIFIED RIGHT.
+";
+        let layout = parse_copybook(copybook, Encoding::Ebcdic).unwrap();
+        assert_eq!(
+            vec![
+                ("ACCOUNT-ID", 8, 0),
+                ("ACCOUNT-NAME", 20, 0),
+                ("BALANCE", 9, 2),
+                ("RATE", 3, 4),
+                ("BRANCH", 2, 0),
+                ("OPENED", 8, 0),
+                ("CODE-R", 3, 0),
+            ],
+            summary(&layout)
+        );
+
+        let fields: &[Field] = layout.fields();
+        assert_eq!(
+            (
+                Alignment::Right,
+                Symbol::Zero,
+                &FieldKind::Zoned(Encoding::Ebcdic)
+            ),
+            (fields[0].alignment, fields[0].symbol, &fields[0].kind)
+        );
+        assert_eq!(
+            (Alignment::Left, Symbol::Whitespace, &FieldKind::Text),
+            (fields[1].alignment, fields[1].symbol, &fields[1].kind)
+        );
+        assert_eq!(FieldKind::Signed(Encoding::Ebcdic), fields[2].kind);
+        assert_eq!(FieldKind::Packed { signed: false }, fields[3].kind);
+        assert_eq!(
+            FieldKind::Binary {
+                signed: true,
+                endianness: Endianness::Big,
+            },
+            fields[4].kind
+        );
+        assert_eq!(Alignment::Right, fields[6].alignment);
+    }
+
+    #[test]
+    fn copybook_occurs_redefines_and_filler() {
+        let copybook = "
+       01  ORDER-RECORD.
+           05  ORDER-ID            PIC 9(5).
+           05  ORDER-DATE          PIC X(8).
+           05  ORDER-DATE-R REDEFINES ORDER-DATE.
+               10  ORDER-YEAR      PIC 9(4).
+               10  FILLER          PIC X(4).
+           05  LINE-ITEM OCCURS 2 TIMES.
+               10  SKU             PIC X(4).
+               10  QTY             PIC 9(3) OCCURS 2.
+           05  FILLER              PIC X(3).
+           88  IS-EMPTY            VALUE SPACES.
+       01  TRAILER-RECORD.
+           05  RECORD-COUNT        PIC 9(9).
+";
+        let layout = parse_copybook(copybook, Encoding::Ascii).unwrap();
+        assert_eq!(
+            vec![
+                ("ORDER-ID", 5, 0),
+                ("ORDER-DATE", 8, 0),
+                ("SKU(1)", 4, 0),
+                ("QTY(1,1)", 3, 0),
+                ("QTY(1,2)", 3, 0),
+                ("SKU(2)", 4, 0),
+                ("QTY(2,1)", 3, 0),
+                ("QTY(2,2)", 3, 0),
+                ("FILLER", 3, 0),
+            ],
+            summary(&layout)
+        );
+        assert_eq!(36, layout.width());
+    }
+
+    #[test]
+    fn copybook_group_usage_and_fixed_format() {
+        let copybook = "\
+000100 01  TOTALS.                                                      TOTALS01
+000200* Both totals are packed through the usage of the group.
+000300     05  AMOUNTS COMP-3.                                          TOTALS01
+000400         10  DEBIT         PIC S9(9)V99.                          TOTALS01
+000500         10  CREDIT        PIC S9(9)V99.                          TOTALS01
+";
+        let layout = parse_copybook(copybook, Encoding::Ebcdic).unwrap();
+        assert_eq!(vec![("DEBIT", 6, 2), ("CREDIT", 6, 2)], summary(&layout));
+        assert_eq!(FieldKind::Packed { signed: true }, layout.fields()[1].kind);
+    }
+
+    #[test]
+    fn copybook_fixed_format_non_ascii() {
+        // Byte 72 falls inside one of the é, while the period is in column 63.
+        let code: String = format!(
+            "{:<33}'{}'.",
+            "    05  NAME    PIC X(20) VALUE",
+            "é".repeat(20)
+        );
+        let code: String = format!("{:<65}", code);
+        let copybook: String = format!("000100 01  REC.\n000200 {}NAMEID01\n", code);
+        let layout = parse_copybook(&copybook, Encoding::Ascii).unwrap();
+        assert_eq!(vec![("NAME", 20, 0)], summary(&layout));
+
+        let copybook = "000100é 01 REC.\n000200  05 ID PIC 9(3).\n";
+        let layout = parse_copybook(copybook, Encoding::Ascii).unwrap();
+        assert_eq!(vec![("ID", 3, 0)], summary(&layout));
+    }
+
+    #[test]
+    fn copybook_without_record_level() {
+        let copybook = "
+    05 NAME PIC X(4). *> The name of the customer.
+    05 AGE  PIC 999.
+";
+        let layout = parse_copybook(copybook, Encoding::Ascii).unwrap();
+        assert_eq!(vec![("NAME", 4, 0), ("AGE", 3, 0)], summary(&layout));
+    }
+
+    #[test]
+    fn copybook_round_trip() {
+        let copybook = "
+       01  PAYMENT.
+           05  PAYEE     PIC X(6).
+           05  AMOUNT    PIC S9(5)V99.
+           05  FEE       PIC 9(3)V99 COMP-3.
+";
+        let layout = parse_copybook(copybook, Encoding::Ascii).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        layout
+            .write(
+                &[
+                    crate::Value::from("ACME"),
+                    crate::Value::from("-12.5"),
+                    crate::Value::from("3.25"),
+                ],
+                &mut buffer,
+            )
+            .unwrap();
+        assert_eq!(b"ACME  000125}\x00\x32\x5F", buffer.as_slice());
+        assert_eq!(
+            crate::Value::Decimal(-1250, 2),
+            layout.decode(&buffer).unwrap()[1]
+        );
+    }
+
+    #[test]
+    fn copybook_unsigned_ebcdic() {
+        let copybook = "
+       01  ITEM.
+           05  QUANTITY  PIC 9(4)V9.
+";
+        let layout = parse_copybook(copybook, Encoding::Ebcdic).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        layout
+            .write(&[crate::Value::from("12.5")], &mut buffer)
+            .unwrap();
+        assert_eq!(b"\xF0\xF0\xF1\xF2\xF5", buffer.as_slice());
+        assert_eq!(
+            crate::Value::Decimal(125, 1),
+            layout.decode(&buffer).unwrap()[0]
+        );
+        assert!(layout.decode(b"00125").is_err());
+    }
+
+    #[test]
+    fn copybook_errors() {
+        let output = parse_copybook("01 REC.\n 05 A PIC X(2) COMP-3.", Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let output = parse_copybook("01 REC.\n 05 A PIC 9(2) OCCURS 1 TO 5.", Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let output = parse_copybook("01 REC.\n 05 A PIC 9(2) COMP-1.", Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let output = parse_copybook("01 REC.\n 05 A.", Encoding::Ascii);
+        assert_eq!(
+            Err(PadderError::InvalidLayout(
+                "copybook line 2: 'A' has no picture".to_string()
+            )),
+            output
+        );
+        let output = parse_copybook("", Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+
+        let output = parse_copybook("01 REC.\n 05 A PIC X OCCURS 100000000.", Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let copybook = "01 REC.\n 05 T OCCURS 1000.\n 10 U OCCURS 1000.\n 15 A PIC X.";
+        let output = parse_copybook(copybook, Encoding::Ascii);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let copybook = "01 REC.\n 05 A PIC X(18446744073709551615).\n 05 B PIC X.";
+        let output = parse_copybook(copybook, Encoding::Ascii);
+        assert_eq!(
+            Err(PadderError::InvalidLayout(
+                "the record is too wide".to_string()
+            )),
+            output
+        );
+    }
+}
@@ -0,0 +1,778 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Date and time formatting into fixed-width fields.
+//!
+//! A [`DateTimeFormat`] is created from a pattern built from the tokens `YYYY`, `YY`,
+//! `MM`, `DD`, `HH` and `SS`, where any other character is written as is. The token `MM`
+//! means minutes when it directly follows an `HH` token, and months otherwise, so both
+//! `YYYYMMDD` and `YYYY-MM-DDTHH:MM:SS` behave as expected. Missing values are written
+//! as a field consisting only of the fill [`Symbol`].
+//!
+//! ```
+//! use padder::*;
+//!
+//! let format = DateTimeFormat::new("YYYY-MM-DDTHH:MM:SS");
+//! let value = DateTime::new(2024, 5, 10, 13, 7, 0);
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(2 * format.width());
+//! format.write(Some(&value), Symbol::Whitespace, &mut buffer).unwrap();
+//! format.write(None, Symbol::Whitespace, &mut buffer).unwrap();
+//!
+//! let mut expected = b"2024-05-10T13:07:00".to_vec();
+//! expected.extend_from_slice(&[b' '; 19]);
+//! assert_eq!(expected, buffer);
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::str;
+
+use crate::zoned::{decode_zoned, encode_zoned, Encoding};
+use crate::{pad_and_push_to_buffer, Alignment, PadderError, Symbol};
+
+/// Plain date and time components, where unused components can be left as zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// Get the number of days in the month of the year, where the month is 1 to 12.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if matches!((year % 4, year % 100, year % 400), (0, 1.., _) | (_, _, 0)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl DateTime {
+    /// Create a new [`DateTime`] from all of its components.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Create a new [`DateTime`] from only the date components.
+    pub fn date(year: u16, month: u8, day: u8) -> Self {
+        Self::new(year, month, day, 0, 0, 0)
+    }
+
+    /// Create a new [`DateTime`] from only the time components.
+    pub fn time(hour: u8, minute: u8, second: u8) -> Self {
+        Self::new(0, 0, 0, hour, minute, second)
+    }
+
+    /// Create a new [`DateTime`] from the number of days since the Unix epoch, that is,
+    /// 1970-01-01, failing if the year is not within 0 to 9999.
+    pub fn from_unix_days(days: i64) -> Result<Self, PadderError> {
+        // Shift the epoch to 0000-03-01 so that leap days end each 400 year era.
+        let days: i64 = days.checked_add(719_468).ok_or(PadderError::OutOfRange)?;
+        let era: i64 = days.div_euclid(146_097);
+        let day_of_era: i64 = days.rem_euclid(146_097);
+        let year_of_era: i64 =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year: i64 =
+            day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index: i64 = (5 * day_of_year + 2) / 153;
+        let day: i64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month: i64 = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+        if !(0..=9999).contains(&year) {
+            return Err(PadderError::OutOfRange);
+        }
+        Ok(Self::date(year as u16, month as u8, day as u8))
+    }
+
+    /// Create a new [`DateTime`] from the number of seconds since the Unix epoch, failing
+    /// if the year is not within 0 to 9999.
+    pub fn from_unix_seconds(seconds: i64) -> Result<Self, PadderError> {
+        let mut datetime: Self = Self::from_unix_days(seconds.div_euclid(86_400))?;
+        let second: i64 = seconds.rem_euclid(86_400);
+        datetime.hour = (second / 3600) as u8;
+        datetime.minute = (second / 60 % 60) as u8;
+        datetime.second = (second % 60) as u8;
+        Ok(datetime)
+    }
+
+    /// Get the number of days since the Unix epoch of the date components, failing if
+    /// the month is out of range or the day is not within the month.
+    pub fn unix_days(&self) -> Result<i64, PadderError> {
+        if !(1..=12).contains(&self.month)
+            || !(1..=days_in_month(self.year, self.month)).contains(&self.day)
+        {
+            return Err(PadderError::InvalidDateTime);
+        }
+        let month: i64 = self.month as i64;
+        let year: i64 = self.year as i64 - i64::from(month <= 2);
+        let era: i64 = year.div_euclid(400);
+        let year_of_era: i64 = year.rem_euclid(400);
+        let month_index: i64 = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year: i64 = (153 * month_index + 2) / 5 + self.day as i64 - 1;
+        let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Ok(era * 146_097 + day_of_era - 719_468)
+    }
+
+    /// Get the number of seconds since the Unix epoch of all components, failing if the
+    /// date is not valid or the time is out of range.
+    pub fn unix_seconds(&self) -> Result<i64, PadderError> {
+        if self.hour >= 24 || self.minute >= 60 || self.second >= 60 {
+            return Err(PadderError::InvalidDateTime);
+        }
+        let time: i64 = self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64;
+        Ok(self.unix_days()? * 86_400 + time)
+    }
+}
+
+/// Format the components in ISO 8601 style, as `YYYY-MM-DD` if all time components are
+/// zero, as `HH:MM:SS` if all date components are zero, and as `YYYY-MM-DDTHH:MM:SS`
+/// otherwise.
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date: bool = self.year != 0 || self.month != 0 || self.day != 0;
+        let time: bool = self.hour != 0 || self.minute != 0 || self.second != 0;
+        if date {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)?;
+        }
+        if date && time {
+            write!(f, "T")?;
+        }
+        if time || !date {
+            write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse the components from ISO 8601 style `YYYY-MM-DD`, `HH:MM:SS`, or
+/// `YYYY-MM-DDTHH:MM:SS` where the `T` may also be a space.
+impl str::FromStr for DateTime {
+    type Err = PadderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pattern: &str = match (s.len(), s.as_bytes().get(10)) {
+            (8, _) => "HH:MM:SS",
+            (10, _) => "YYYY-MM-DD",
+            (19, Some(b' ')) => "YYYY-MM-DD HH:MM:SS",
+            (19, _) => "YYYY-MM-DDTHH:MM:SS",
+            _ => return Err(PadderError::InvalidDateTime),
+        };
+
+        DateTimeFormat::new(pattern)
+            .parse(s.as_bytes(), Symbol::Whitespace)
+            .ok()
+            .flatten()
+            .ok_or(PadderError::InvalidDateTime)
+    }
+}
+
+/// The components of a parsed date and time pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Year4,
+    Year2,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Literal(u8),
+}
+
+impl Token {
+    /// Get the number of positions the token occupies in the field.
+    fn width(&self) -> usize {
+        match self {
+            Self::Year4 => 4,
+            Self::Literal(_) => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// A parsed date and time pattern, used to write and parse fixed-width fields.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(from = "String", into = "String")
+)]
+pub struct DateTimeFormat {
+    pattern: String,
+    tokens: Vec<Token>,
+}
+
+impl DateTimeFormat {
+    /// Parse the pattern into a new [`DateTimeFormat`].
+    pub fn new(pattern: &str) -> Self {
+        let bytes: &[u8] = pattern.as_bytes();
+        let mut tokens: Vec<Token> = Vec::with_capacity(bytes.len());
+        let mut previous: Option<Token> = None;
+        let mut idx: usize = 0;
+
+        while idx < bytes.len() {
+            let rest: &[u8] = &bytes[idx..];
+            let token: Token = if rest.starts_with(b"YYYY") {
+                Token::Year4
+            } else if rest.starts_with(b"YY") {
+                Token::Year2
+            } else if rest.starts_with(b"MM") && previous == Some(Token::Hour) {
+                Token::Minute
+            } else if rest.starts_with(b"MM") {
+                Token::Month
+            } else if rest.starts_with(b"DD") {
+                Token::Day
+            } else if rest.starts_with(b"HH") {
+                Token::Hour
+            } else if rest.starts_with(b"SS") {
+                Token::Second
+            } else {
+                Token::Literal(rest[0])
+            };
+
+            idx += token.width();
+            if !matches!(token, Token::Literal(_)) {
+                previous = Some(token);
+            }
+            tokens.push(token);
+        }
+
+        Self {
+            pattern: pattern.to_string(),
+            tokens,
+        }
+    }
+
+    /// Get the pattern that the format was created from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Get the width of the fields written with the format.
+    pub fn width(&self) -> usize {
+        self.tokens.iter().map(Token::width).sum()
+    }
+
+    /// Check whether the format has any of the year, month and day tokens.
+    pub fn has_date(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|t| matches!(t, Token::Year4 | Token::Year2 | Token::Month | Token::Day))
+    }
+
+    /// Check whether the format has any of the hour, minute and second tokens.
+    pub fn has_time(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|t| matches!(t, Token::Hour | Token::Minute | Token::Second))
+    }
+
+    /// Format the value and push it to the buffer, or fill the whole field with the
+    /// symbol if the value is missing.
+    ///
+    /// Returns an error if a component is out of range, including a day that does not
+    /// exist in its month.
+    pub fn write(
+        &self,
+        value: Option<&DateTime>,
+        fill: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        let value: &DateTime = match value {
+            Some(value) => value,
+            None => {
+                let empty: &[u8] = &[];
+                pad_and_push_to_buffer(empty, self.width(), Alignment::Left, fill, buffer);
+                return Ok(());
+            }
+        };
+
+        // The day is checked against the month like in parse, so that writes read back.
+        let days: u8 = match value.month {
+            1..=12 => days_in_month(value.year, value.month),
+            _ => 31,
+        };
+        let start: usize = buffer.len();
+        for token in self.tokens.iter() {
+            let component: u64 = match token {
+                Token::Year4 => value.year as u64,
+                Token::Year2 => (value.year % 100) as u64,
+                Token::Month if (1..=12).contains(&value.month) => value.month as u64,
+                Token::Day if (1..=days).contains(&value.day) => value.day as u64,
+                Token::Hour if value.hour < 24 => value.hour as u64,
+                Token::Minute if value.minute < 60 => value.minute as u64,
+                Token::Second if value.second < 60 => value.second as u64,
+                Token::Literal(byte) => {
+                    buffer.push(*byte);
+                    continue;
+                }
+                _ => {
+                    buffer.truncate(start);
+                    return Err(PadderError::InvalidDateTime);
+                }
+            };
+
+            if let Err(error) = encode_zoned(component, token.width(), Encoding::Ascii, buffer) {
+                buffer.truncate(start);
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a field written with the format, where a field consisting only of the fill
+    /// symbol is parsed as a missing value.
+    ///
+    /// Returns an error if a component is out of range, including a day that does not
+    /// exist in its month.
+    ///
+    /// Two digit years are parsed into the years 1950 to 2049. Note that when filling with
+    /// [`Symbol::Zero`], a time field at midnight is indistinguishable from a missing value.
+    pub fn parse(&self, field: &[u8], fill: Symbol) -> Result<Option<DateTime>, PadderError> {
+        if field.len() != self.width() {
+            return Err(PadderError::InvalidLength(field.len()));
+        }
+
+        let fill: u8 = fill.into();
+        if field.iter().all(|&b| b == fill) {
+            return Ok(None);
+        }
+
+        let mut value = DateTime::default();
+        let mut idx: usize = 0;
+        for token in self.tokens.iter() {
+            let part: &[u8] = &field[idx..(idx + token.width())];
+            idx += token.width();
+
+            if let Token::Literal(byte) = token {
+                if part[0] != *byte {
+                    return Err(PadderError::InvalidDateTime);
+                }
+                continue;
+            }
+
+            let component: u64 = decode_zoned(part, Encoding::Ascii)?;
+            let valid: bool = match token {
+                Token::Month => (1..=12).contains(&component),
+                Token::Day => (1..=31).contains(&component),
+                Token::Hour => component < 24,
+                Token::Minute | Token::Second => component < 60,
+                _ => true,
+            };
+            if !valid {
+                return Err(PadderError::InvalidDateTime);
+            }
+            match token {
+                Token::Year4 => value.year = component as u16,
+                Token::Year2 if component < 50 => value.year = 2000 + component as u16,
+                Token::Year2 => value.year = 1900 + component as u16,
+                Token::Month => value.month = component as u8,
+                Token::Day => value.day = component as u8,
+                Token::Hour => value.hour = component as u8,
+                Token::Minute => value.minute = component as u8,
+                Token::Second => value.second = component as u8,
+                Token::Literal(_) => unreachable!(),
+            }
+        }
+
+        if value.month != 0 && value.day > days_in_month(value.year, value.month) {
+            return Err(PadderError::InvalidDateTime);
+        }
+        Ok(Some(value))
+    }
+}
+
+/// Parse the pattern into a new [`DateTimeFormat`].
+impl From<String> for DateTimeFormat {
+    fn from(pattern: String) -> Self {
+        Self::new(&pattern)
+    }
+}
+
+/// Convert the [`DateTimeFormat`] back into its pattern.
+impl From<DateTimeFormat> for String {
+    fn from(format: DateTimeFormat) -> Self {
+        format.pattern
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use super::DateTime;
+    use crate::PadderError;
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    /// Convert a [`NaiveDate`] into its date components, failing if the year is not
+    /// within 0 to 9999.
+    impl TryFrom<NaiveDate> for DateTime {
+        type Error = PadderError;
+
+        fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+            let year: u16 = u16::try_from(date.year())
+                .ok()
+                .filter(|year| *year <= 9999)
+                .ok_or(PadderError::OutOfRange)?;
+            Ok(Self::date(year, date.month() as u8, date.day() as u8))
+        }
+    }
+
+    /// Convert a [`NaiveTime`] into its time components.
+    impl From<NaiveTime> for DateTime {
+        fn from(time: NaiveTime) -> Self {
+            Self::time(time.hour() as u8, time.minute() as u8, time.second() as u8)
+        }
+    }
+
+    /// Convert a [`NaiveDateTime`] into its date and time components, failing if the
+    /// year is not within 0 to 9999.
+    impl TryFrom<NaiveDateTime> for DateTime {
+        type Error = PadderError;
+
+        fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+            Ok(Self {
+                hour: datetime.hour() as u8,
+                minute: datetime.minute() as u8,
+                second: datetime.second() as u8,
+                ..Self::try_from(datetime.date())?
+            })
+        }
+    }
+
+    /// Convert the date components into a [`NaiveDate`], failing if they are invalid.
+    impl TryFrom<DateTime> for NaiveDate {
+        type Error = PadderError;
+
+        fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+            NaiveDate::from_ymd_opt(value.year as i32, value.month as u32, value.day as u32)
+                .ok_or(PadderError::InvalidDateTime)
+        }
+    }
+
+    /// Convert the date and time components into a [`NaiveDateTime`], failing if they
+    /// are invalid.
+    impl TryFrom<DateTime> for NaiveDateTime {
+        type Error = PadderError;
+
+        fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+            let time = NaiveTime::from_hms_opt(
+                value.hour as u32,
+                value.minute as u32,
+                value.second as u32,
+            )
+            .ok_or(PadderError::InvalidDateTime)?;
+            Ok(NaiveDate::try_from(value)?.and_time(time))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_width() {
+        assert_eq!(8, DateTimeFormat::new("YYYYMMDD").width());
+        assert_eq!(6, DateTimeFormat::new("DDMMYY").width());
+        assert_eq!(19, DateTimeFormat::new("YYYY-MM-DDTHH:MM:SS").width());
+    }
+
+    #[test]
+    fn write_yyyymmdd() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let format = DateTimeFormat::new("YYYYMMDD");
+        format
+            .write(Some(&DateTime::date(1999, 1, 9)), Symbol::Zero, &mut buffer)
+            .unwrap();
+        assert_eq!(b"19990109", buffer.as_slice());
+    }
+
+    #[test]
+    fn format_components() {
+        let format = DateTimeFormat::new("YYYYMMDD");
+        assert!(format.has_date() && !format.has_time());
+        let format = DateTimeFormat::new("HH:MM");
+        assert!(!format.has_date() && format.has_time());
+        let format = DateTimeFormat::new("DD.MM.YY HH");
+        assert!(format.has_date() && format.has_time());
+    }
+
+    #[test]
+    fn write_ddmmyy() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let format = DateTimeFormat::new("DDMMYY");
+        format
+            .write(
+                Some(&DateTime::date(2005, 12, 31)),
+                Symbol::Zero,
+                &mut buffer,
+            )
+            .unwrap();
+        assert_eq!(b"311205", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_hhmmss() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let format = DateTimeFormat::new("HHMMSS");
+        format
+            .write(Some(&DateTime::time(7, 45, 3)), Symbol::Zero, &mut buffer)
+            .unwrap();
+        assert_eq!(b"074503", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_missing_zero_fill() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let format = DateTimeFormat::new("YYYYMMDD");
+        format.write(None, Symbol::Zero, &mut buffer).unwrap();
+        assert_eq!(b"00000000", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_invalid_month() {
+        let mut buffer: Vec<u8> = b"abc".to_vec();
+        let format = DateTimeFormat::new("YYYYMMDD");
+        let output = format.write(
+            Some(&DateTime::date(2024, 13, 1)),
+            Symbol::Zero,
+            &mut buffer,
+        );
+        assert_eq!(Err(PadderError::InvalidDateTime), output);
+        assert_eq!(b"abc", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_year_overflow() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let format = DateTimeFormat::new("YYYYMMDD");
+        let output = format.write(
+            Some(&DateTime::date(10000, 1, 1)),
+            Symbol::Zero,
+            &mut buffer,
+        );
+        let expected = Err(PadderError::Overflow {
+            width: 4,
+            required: 5,
+        });
+        assert_eq!(expected, output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let format = DateTimeFormat::new("YYYY-MM-DDTHH:MM:SS");
+        let value = DateTime::new(2024, 2, 29, 23, 59, 58);
+        let mut buffer: Vec<u8> = Vec::new();
+        format
+            .write(Some(&value), Symbol::Whitespace, &mut buffer)
+            .unwrap();
+        assert_eq!(Ok(Some(value)), format.parse(&buffer, Symbol::Whitespace));
+    }
+
+    #[test]
+    fn parse_two_digit_year() {
+        let format = DateTimeFormat::new("DDMMYY");
+        let output = format.parse(b"010149", Symbol::Zero).unwrap();
+        assert_eq!(Some(DateTime::date(2049, 1, 1)), output);
+        let output = format.parse(b"010150", Symbol::Zero).unwrap();
+        assert_eq!(Some(DateTime::date(1950, 1, 1)), output);
+    }
+
+    #[test]
+    fn parse_missing() {
+        let format = DateTimeFormat::new("HHMMSS");
+        assert_eq!(Ok(None), format.parse(b"      ", Symbol::Whitespace));
+    }
+
+    #[test]
+    fn parse_invalid_literal() {
+        let format = DateTimeFormat::new("YYYY-MM-DD");
+        let output = format.parse(b"2024/01/01", Symbol::Whitespace);
+        assert_eq!(Err(PadderError::InvalidDateTime), output);
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        let format = DateTimeFormat::new("YYYYMMDDHHMMSS");
+        for field in [
+            "20241301000000",
+            "20240199000000",
+            "20240100000000",
+            "20230229000000",
+            "20240431000000",
+            "20240101990000",
+            "20240101006000",
+            "20240101000060",
+        ] {
+            let output = format.parse(field.as_bytes(), Symbol::Whitespace);
+            assert_eq!(Err(PadderError::InvalidDateTime), output, "{}", field);
+        }
+        let output = format.parse(b"20240229235959", Symbol::Whitespace);
+        assert_eq!(Ok(Some(DateTime::new(2024, 2, 29, 23, 59, 59))), output);
+        let output = DateTimeFormat::new("DD").parse(b"31", Symbol::Whitespace);
+        assert_eq!(Ok(Some(DateTime::date(0, 0, 31))), output);
+    }
+
+    #[test]
+    fn write_out_of_range() {
+        let format = DateTimeFormat::new("YYYYMMDD");
+        let mut buffer: Vec<u8> = b"x".to_vec();
+        for value in [
+            DateTime::date(2024, 2, 30),
+            DateTime::date(2023, 2, 29),
+            DateTime::date(2024, 4, 31),
+            DateTime::date(2024, 13, 1),
+        ] {
+            let output = format.write(Some(&value), Symbol::Whitespace, &mut buffer);
+            assert_eq!(Err(PadderError::InvalidDateTime), output, "{}", value);
+        }
+        assert_eq!(b"x", buffer.as_slice());
+
+        let value = DateTime::date(2024, 2, 29);
+        format
+            .write(Some(&value), Symbol::Whitespace, &mut buffer)
+            .unwrap();
+        let output = format.parse(&buffer[1..], Symbol::Whitespace);
+        assert_eq!(Ok(Some(value)), output);
+    }
+
+    #[test]
+    fn display_datetime() {
+        assert_eq!("2024-05-10", DateTime::date(2024, 5, 10).to_string());
+        assert_eq!("08:30:00", DateTime::time(8, 30, 0).to_string());
+        assert_eq!("00:00:00", DateTime::default().to_string());
+        let value = DateTime::new(2024, 5, 10, 8, 30, 1);
+        assert_eq!("2024-05-10T08:30:01", value.to_string());
+    }
+
+    #[test]
+    fn from_str_datetime() {
+        assert_eq!(Ok(DateTime::date(2024, 5, 10)), "2024-05-10".parse());
+        assert_eq!(Ok(DateTime::time(8, 30, 0)), "08:30:00".parse());
+        let expected = Ok(DateTime::new(2024, 5, 10, 8, 30, 1));
+        assert_eq!(expected, "2024-05-10T08:30:01".parse());
+        assert_eq!(expected, "2024-05-10 08:30:01".parse());
+        assert_eq!(
+            Err(PadderError::InvalidDateTime),
+            "10/05/2024".parse::<DateTime>()
+        );
+    }
+
+    #[test]
+    fn unix_days_round_trip() {
+        for (days, expected) in [
+            (0, DateTime::date(1970, 1, 1)),
+            (-1, DateTime::date(1969, 12, 31)),
+            (11_016, DateTime::date(2000, 2, 29)),
+            (19_853, DateTime::date(2024, 5, 10)),
+            (-719_528, DateTime::date(0, 1, 1)),
+            (2_932_896, DateTime::date(9999, 12, 31)),
+        ] {
+            assert_eq!(Ok(expected), DateTime::from_unix_days(days));
+            assert_eq!(Ok(days), expected.unix_days());
+        }
+        assert_eq!(
+            Err(PadderError::OutOfRange),
+            DateTime::from_unix_days(2_932_897)
+        );
+        assert_eq!(
+            Err(PadderError::OutOfRange),
+            DateTime::from_unix_days(i64::MAX)
+        );
+        assert_eq!(
+            Err(PadderError::InvalidDateTime),
+            DateTime::time(8, 0, 0).unix_days()
+        );
+        for invalid in [
+            DateTime::date(2024, 2, 30),
+            DateTime::date(2023, 2, 29),
+            DateTime::date(1900, 2, 29),
+            DateTime::date(2024, 4, 31),
+        ] {
+            assert_eq!(Err(PadderError::InvalidDateTime), invalid.unix_days());
+        }
+    }
+
+    #[test]
+    fn unix_seconds_round_trip() {
+        let value = DateTime::new(2024, 5, 10, 8, 30, 1);
+        assert_eq!(Ok(1_715_329_801), value.unix_seconds());
+        assert_eq!(Ok(value), DateTime::from_unix_seconds(1_715_329_801));
+        let value = DateTime::new(1969, 12, 31, 23, 59, 59);
+        assert_eq!(Ok(value), DateTime::from_unix_seconds(-1));
+        assert_eq!(
+            Err(PadderError::InvalidDateTime),
+            DateTime::new(2024, 5, 10, 24, 0, 0).unix_seconds()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_round_trip() {
+        use chrono::NaiveDate;
+
+        let date = NaiveDate::from_ymd_opt(2023, 12, 14).unwrap();
+        let format = DateTimeFormat::new("YYYYMMDD");
+        let mut buffer: Vec<u8> = Vec::new();
+        let value = DateTime::try_from(date).unwrap();
+        format
+            .write(Some(&value), Symbol::Zero, &mut buffer)
+            .unwrap();
+        assert_eq!(b"20231214", buffer.as_slice());
+
+        let parsed = format.parse(&buffer, Symbol::Zero).unwrap().unwrap();
+        assert_eq!(Ok(date), NaiveDate::try_from(parsed));
+
+        let date = NaiveDate::from_ymd_opt(70000, 1, 1).unwrap();
+        assert_eq!(Err(PadderError::OutOfRange), DateTime::try_from(date));
+        let date = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap();
+        assert_eq!(Err(PadderError::OutOfRange), DateTime::try_from(date));
+    }
+}
@@ -0,0 +1,626 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Deserialize any type implementing [`serde::Deserialize`] from a fixed-width record.
+//!
+//! The record is sliced into fields and decoded by the [`Layout`], which strips the
+//! padding according to the alignment and symbol of each field and decodes numbers,
+//! booleans and dates according to its kind. Structs and maps get the values by the names
+//! of the fields, while sequences and tuples get them in order. Text is borrowed from the
+//! record whenever the target type allows it.
+//!
+//! ```
+//! use padder::*;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize, Debug, PartialEq)]
+//! struct Payment<'a> {
+//!     payee: &'a str,
+//!     amount: f64,
+//!     reference: Option<u32>,
+//! }
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("payee", 8, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 7, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//!     Field::new("reference", 4, Alignment::Right, Symbol::Whitespace, FieldKind::Numeric),
+//! ]);
+//!
+//! let payment: Payment = from_record(b"acme    0001250    ", &layout).unwrap();
+//! assert_eq!(Payment { payee: "acme", amount: 12.5, reference: None }, payment);
+//! ```
+//!
+
+use std::borrow::Cow;
+use std::vec;
+
+use serde::de::value::{BorrowedStrDeserializer, StringDeserializer};
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+use crate::layout::Layout;
+use crate::value::Value;
+use crate::PadderError;
+
+/// Deserialize a value from a record with the given layout.
+pub fn from_record<'de, T: Deserialize<'de>>(
+    record: &'de [u8],
+    layout: &Layout,
+) -> Result<T, PadderError> {
+    T::deserialize(RecordDeserializer::new(record, layout)?)
+}
+
+/// A [`serde::Deserializer`] that reads a struct, map, sequence or tuple from one record
+/// with the fields of a [`Layout`].
+pub struct RecordDeserializer<'de, 'a> {
+    layout: &'a Layout,
+    values: Vec<Value<'de>>,
+}
+
+impl<'de, 'a> RecordDeserializer<'de, 'a> {
+    /// Create a new [`RecordDeserializer`] by decoding the record with the layout.
+    pub fn new(record: &'de [u8], layout: &'a Layout) -> Result<Self, PadderError> {
+        let values: Vec<Value<'de>> = layout.decode(record)?;
+        Ok(Self { layout, values })
+    }
+
+    /// Hand out the decoded values together with the names of their fields.
+    fn access(self) -> RecordAccess<'de, 'a> {
+        let names: Vec<&'a str> = self
+            .layout
+            .fields()
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        RecordAccess {
+            names: names.into_iter(),
+            values: self.values.into_iter(),
+            name: "",
+            value: None,
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for RecordDeserializer<'de, '_> {
+    type Error = PadderError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_map(self.access())
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_seq(self.access())
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+        byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// Hands out the decoded values of a record, as map entries or sequence elements.
+struct RecordAccess<'de, 'a> {
+    names: vec::IntoIter<&'a str>,
+    values: vec::IntoIter<Value<'de>>,
+    name: &'a str,
+    value: Option<Value<'de>>,
+}
+
+/// Add the name of the field to the error.
+fn in_field(name: &str, error: PadderError) -> PadderError {
+    match error {
+        PadderError::Field { .. } => error,
+        error => PadderError::Field {
+            name: name.to_string(),
+            error: Box::new(error),
+        },
+    }
+}
+
+impl<'de> de::MapAccess<'de> for RecordAccess<'de, '_> {
+    type Error = PadderError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, PadderError> {
+        match (self.names.next(), self.values.next()) {
+            (Some(name), Some(value)) => {
+                self.name = name;
+                self.value = Some(value);
+                let key: StringDeserializer<PadderError> = name.to_string().into_deserializer();
+                seed.deserialize(key).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, PadderError> {
+        let value: Value<'de> = self
+            .value
+            .take()
+            .ok_or_else(|| PadderError::InvalidValue("value without a field".to_string()))?;
+        seed.deserialize(ValueDeserializer(value))
+            .map_err(|error| in_field(self.name, error))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for RecordAccess<'de, '_> {
+    type Error = PadderError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, PadderError> {
+        match (self.names.next(), self.values.next()) {
+            (Some(name), Some(value)) => seed
+                .deserialize(ValueDeserializer(value))
+                .map(Some)
+                .map_err(|error| in_field(name, error)),
+            _ => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+/// A [`serde::Deserializer`] for the decoded value of a single field.
+///
+/// Numbers and booleans are converted to the type asked for, text is parsed when a
+/// number or boolean is asked for, and empty text is a missing value for options.
+struct ValueDeserializer<'de>(Value<'de>);
+
+impl ValueDeserializer<'_> {
+    /// Get the value as an integer, failing if the value is missing or has decimals.
+    fn integer(&self) -> Result<i64, PadderError> {
+        self.0.to_scaled(0)?.ok_or(PadderError::EmptyField)
+    }
+
+    /// Get the value as a float.
+    fn float(&self) -> Result<f64, PadderError> {
+        match &self.0 {
+            Value::Float(value) => Ok(*value),
+            Value::Integer(value) => Ok(*value as f64),
+            Value::Decimal(value, scale) => Ok(*value as f64 / 10f64.powi(*scale as i32)),
+            Value::Null => Err(PadderError::EmptyField),
+            value => {
+                let text: String = value.to_string();
+                match text.trim() {
+                    "" => Err(PadderError::EmptyField),
+                    text => text.parse().map_err(|_| {
+                        PadderError::InvalidValue(format!("'{}' is not a number", text))
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Check whether the value is missing or empty text.
+    fn is_empty(&self) -> bool {
+        match &self.0 {
+            Value::Null => true,
+            Value::Text(text) => text.trim().is_empty(),
+            _ => false,
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = PadderError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Text(Cow::Borrowed(text)) => visitor.visit_borrowed_str(text),
+            Value::Text(Cow::Owned(text)) => visitor.visit_string(text),
+            Value::Bytes(Cow::Borrowed(bytes)) => visitor.visit_borrowed_bytes(bytes),
+            Value::Bytes(Cow::Owned(bytes)) => visitor.visit_byte_buf(bytes),
+            Value::Integer(value) => visitor.visit_i64(value),
+            Value::Decimal(value, 0) => visitor.visit_i64(value),
+            Value::Decimal(..) | Value::Float(_) => visitor.visit_f64(self.float()?),
+            Value::Boolean(value) => visitor.visit_bool(value),
+            Value::DateTime(value) => visitor.visit_string(value.to_string()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        match &self.0 {
+            Value::Boolean(value) => visitor.visit_bool(*value),
+            Value::Text(text) => match text.trim() {
+                "true" => visitor.visit_bool(true),
+                "false" => visitor.visit_bool(false),
+                "" => Err(PadderError::EmptyField),
+                text => Err(PadderError::InvalidValue(format!(
+                    "'{}' is not a boolean",
+                    text
+                ))),
+            },
+            Value::Null => Err(PadderError::EmptyField),
+            value => Err(PadderError::InvalidValue(format!(
+                "'{}' is not a boolean",
+                value
+            ))),
+        }
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_f64(self.float()?)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        visitor.visit_f64(self.float()?)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        match self.0 {
+            Value::Text(Cow::Borrowed(text)) => visitor.visit_borrowed_str(text),
+            Value::Text(Cow::Owned(text)) => visitor.visit_string(text),
+            Value::Bytes(_) => self.deserialize_any(visitor),
+            value => visitor.visit_string(value.to_string()),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        match self.0 {
+            Value::Text(Cow::Borrowed(text)) => visitor.visit_borrowed_bytes(text.as_bytes()),
+            Value::Text(Cow::Owned(text)) => visitor.visit_byte_buf(text.into_bytes()),
+            value => ValueDeserializer(value).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, PadderError> {
+        if self.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, PadderError> {
+        match self.0 {
+            Value::Text(Cow::Borrowed(text)) => {
+                visitor.visit_enum(BorrowedStrDeserializer::new(text.trim()))
+            }
+            value => {
+                let text: String = value.to_string().trim().to_string();
+                visitor.visit_enum(text.into_deserializer())
+            }
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::{Alignment, BoolCodes, DateTimeFormat, Encoding, Symbol};
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Status {
+        Active,
+        Closed,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Customer<'a> {
+        id: u32,
+        name: &'a str,
+        balance: f64,
+        credit: i64,
+        active: bool,
+        joined: String,
+        status: Status,
+        nickname: Option<String>,
+    }
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new("id", 5, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "balance",
+                8,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+            Field::new(
+                "credit",
+                4,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Signed(Encoding::Ascii),
+            ),
+            Field::new(
+                "active",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+            Field::new(
+                "joined",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+            Field::new(
+                "status",
+                6,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "nickname",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "filler",
+                2,
+                Alignment::Left,
+                Symbol::Hyphen,
+                FieldKind::Text,
+            ),
+        ])
+    }
+
+    #[test]
+    fn deserialize_struct() {
+        let record = b"00042Jane    00123450001}Y20240131Closed    --";
+        let customer: Customer = from_record(record, &layout()).unwrap();
+        assert_eq!(
+            Customer {
+                id: 42,
+                name: "Jane",
+                balance: 1234.5,
+                credit: -10,
+                active: true,
+                joined: "2024-01-31".to_string(),
+                status: Status::Closed,
+                nickname: None,
+            },
+            customer
+        );
+
+        let record = b"00042Jane    00123450001}Y20240131Active    --";
+        let customer: Customer = from_record(record, &layout()).unwrap();
+        assert_eq!(Status::Active, customer.status);
+    }
+
+    #[test]
+    fn deserialize_tuple_and_map() {
+        let layout = Layout::new(vec![
+            Field::new("a", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new("b", 3, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+        ]);
+
+        let output: (u8, String) = from_record(b"007xy ", &layout).unwrap();
+        assert_eq!((7, "xy".to_string()), output);
+
+        let output: BTreeMap<String, String> = from_record(b"007xy ", &layout).unwrap();
+        assert_eq!(Some(&"7".to_string()), output.get("a"));
+        assert_eq!(Some(&"xy".to_string()), output.get("b"));
+    }
+
+    #[test]
+    fn deserialize_text_as_numbers() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Row {
+            count: u16,
+            ratio: f32,
+            flag: bool,
+        }
+
+        let layout = Layout::new(vec![
+            Field::new(
+                "count",
+                4,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "ratio",
+                4,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "flag",
+                5,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+        ]);
+        let output: Row = from_record(b"  12 0.5true ", &layout).unwrap();
+        assert_eq!(
+            Row {
+                count: 12,
+                ratio: 0.5,
+                flag: true,
+            },
+            output
+        );
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Row {
+            a: u8,
+        }
+
+        let layout = Layout::new(vec![Field::new(
+            "a",
+            3,
+            Alignment::Right,
+            Symbol::Whitespace,
+            FieldKind::Numeric,
+        )]);
+
+        let output = from_record::<Row>(b"300", &layout);
+        assert!(matches!(output, Err(PadderError::Field { ref name, .. }) if name == "a"));
+
+        let output = from_record::<Row>(b"   ", &layout);
+        assert_eq!(
+            Err(PadderError::Field {
+                name: "a".to_string(),
+                error: Box::new(PadderError::EmptyField),
+            }),
+            output.map(|_| ())
+        );
+
+        let output = from_record::<Row>(b"12", &layout);
+        assert!(matches!(output, Err(PadderError::RecordLength { .. })));
+    }
+}
@@ -0,0 +1,149 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! The error type returned by the fallible encoding and decoding functions of this crate.
+//!
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Exhaustive enum for the errors that can occur when encoding or decoding fields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PadderError {
+    /// The value requires more positions than the target width allows.
+    Overflow { width: usize, required: usize },
+    /// The value does not fit in the numeric type it is decoded into.
+    OutOfRange,
+    /// The field to decode contained no data.
+    EmptyField,
+    /// The byte at the given position is not a valid digit for the encoding.
+    InvalidDigit { position: usize, byte: u8 },
+    /// The byte carrying the sign of the field could not be decoded.
+    InvalidSign(u8),
+    /// The byte length is not supported for the field encoding.
+    InvalidLength(usize),
+    /// The date or time components are invalid or do not match the format.
+    InvalidDateTime,
+    /// The value or code does not exist in the code mapping.
+    UnknownCode(String),
+    /// The value cannot be converted to what the field expects.
+    InvalidValue(String),
+    /// The number of values does not match the number of fields in the layout.
+    FieldCount { expected: usize, found: usize },
+    /// The length of the record does not match the width of the layout.
+    RecordLength { expected: usize, found: usize },
+    /// The layout spec could not be read or describes an invalid layout.
+    InvalidLayout(String),
+    /// An I/O operation failed, keeping the kind and message of the original error.
+    Io {
+        kind: io::ErrorKind,
+        message: String,
+    },
+    /// The error occurred in the record at the given line and byte offset of the input.
+    Record {
+        line: u64,
+        offset: u64,
+        error: Box<PadderError>,
+    },
+    /// The error occurred in the field with the given name.
+    Field {
+        name: String,
+        error: Box<PadderError>,
+    },
+}
+
+impl fmt::Display for PadderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow { width, required } => write!(
+                f,
+                "value requires {} positions but the width is {}",
+                required, width,
+            ),
+            Self::OutOfRange => write!(f, "value is out of range for the target type"),
+            Self::EmptyField => write!(f, "field is empty"),
+            Self::InvalidDigit { position, byte } => {
+                write!(f, "invalid digit {:#04x} at position {}", byte, position)
+            }
+            Self::InvalidSign(byte) => write!(f, "invalid sign {:#04x}", byte),
+            Self::InvalidLength(length) => write!(f, "invalid field length {}", length),
+            Self::InvalidDateTime => write!(f, "invalid date or time"),
+            Self::UnknownCode(code) => write!(f, "unknown code '{}'", code),
+            Self::InvalidValue(message) => write!(f, "invalid value: {}", message),
+            Self::FieldCount { expected, found } => {
+                write!(f, "expected {} fields but found {}", expected, found)
+            }
+            Self::RecordLength { expected, found } => write!(
+                f,
+                "expected a record length of {} but found {}",
+                expected, found,
+            ),
+            Self::InvalidLayout(message) => write!(f, "invalid layout: {}", message),
+            Self::Io { message, .. } => write!(f, "i/o error: {}", message),
+            Self::Record {
+                line,
+                offset,
+                error,
+            } => write!(f, "line {} (byte offset {}): {}", line, offset, error),
+            Self::Field { name, error } => write!(f, "field '{}': {}", name, error),
+        }
+    }
+}
+
+impl error::Error for PadderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Record { error, .. } | Self::Field { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PadderError {
+    fn from(error: io::Error) -> Self {
+        Self::Io {
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for PadderError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::InvalidValue(message.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for PadderError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::InvalidValue(message.to_string())
+    }
+}
@@ -0,0 +1,341 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Padding of rows to column widths that are computed from the data itself.
+//!
+//! When the widths of a text report are not known in advance, an [`AutoFit`] makes two
+//! passes over the rows: the first computes the widest cell of each column, optionally
+//! capped to a maximum width, and the second pads every cell of the column to that width
+//! with [`Source::pad`]. Rows are slices of cells, which are either strings or slices,
+//! see [`Fit`].
+//!
+//! ```
+//! use padder::*;
+//!
+//! let rows = vec![vec!["id", "name", "city"], vec!["7", "Ström", "Göteborg"]];
+//! let fit = AutoFit::new()
+//!     .with_alignment(Alignment::Left)
+//!     .with_column_alignment(0, Alignment::Right)
+//!     .with_max_width(6);
+//!
+//! assert_eq!(vec![2, 5, 6], fit.widths(&rows));
+//! let output: Vec<Vec<String>> = fit.pad(&rows);
+//! assert_eq!(vec!["id", "name ", "city  "], output[0]);
+//! assert_eq!(vec![" 7", "Ström", "Götebo"], output[1]);
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::clone;
+use std::fmt;
+
+use crate::{Alignment, Source, Symbol};
+
+/// Exhaustive enum for the ways to measure the width of text.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Measure {
+    /// The number of bytes, which is what [`Source::pad`] uses for strings.
+    Bytes,
+    /// The number of characters, so that text with non-ASCII characters lines up.
+    #[default]
+    Chars,
+}
+
+/// A trait for sources that can be measured and padded, or truncated, to a width under
+/// a [`Measure`].
+///
+/// Slices and vectors are always measured by their number of elements, while strings
+/// are measured by bytes or characters and never truncated in the middle of a character.
+pub trait Fit: Source {
+    /// Get the width of the source under the measure.
+    fn width(&self, measure: Measure) -> usize;
+
+    /// Pad the source to the width under the measure with [`Source::pad`], keeping the
+    /// part that [`Source::slice_to_fit`] keeps for the alignment if the source is wider.
+    fn fit(&self, width: usize, mode: Alignment, symbol: Symbol, measure: Measure) -> Self::Output;
+}
+
+impl Fit for &str
+where
+    char: From<Symbol>,
+{
+    fn width(&self, measure: Measure) -> usize {
+        match measure {
+            Measure::Bytes => self.len(),
+            Measure::Chars => self.chars().count(),
+        }
+    }
+
+    fn fit(&self, width: usize, mode: Alignment, symbol: Symbol, measure: Measure) -> Self::Output {
+        let length: usize = self.width(measure);
+        let mut text: &str = self;
+        if length > width {
+            let start: usize = match mode {
+                Alignment::Left => 0,
+                Alignment::Right => length - width,
+                Alignment::Center => length / 2 - width / 2,
+            };
+            // The position of each character boundary under the measure and in bytes.
+            let bounds: Vec<(usize, usize)> = self
+                .char_indices()
+                .enumerate()
+                .map(|(index, (byte, _))| match measure {
+                    Measure::Bytes => (byte, byte),
+                    Measure::Chars => (index, byte),
+                })
+                .chain(std::iter::once((length, self.len())))
+                .collect();
+            let first: usize = bounds.iter().find(|(p, _)| *p >= start).map_or(0, |b| b.1);
+            let last: usize = bounds
+                .iter()
+                .rev()
+                .find(|(p, _)| *p <= start + width)
+                .map_or(0, |b| b.1);
+            text = &self[first..last.max(first)];
+        }
+
+        // Source::pad measures bytes, so the width is adjusted by the multi-byte characters.
+        let bytes: usize = width + text.len() - text.width(measure);
+        text.pad(bytes, mode, symbol)
+    }
+}
+
+impl<T> Fit for &[T]
+where
+    T: From<Symbol> + clone::Clone + fmt::Debug,
+    for<'a> &'a [T]: From<Symbol>,
+{
+    fn width(&self, _measure: Measure) -> usize {
+        self.len()
+    }
+
+    fn fit(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        _measure: Measure,
+    ) -> Self::Output {
+        self.pad(width, mode, symbol)
+    }
+}
+
+impl<T> Fit for Vec<T>
+where
+    T: From<Symbol> + clone::Clone + fmt::Debug,
+    for<'a> &'a [T]: From<Symbol>,
+{
+    fn width(&self, _measure: Measure) -> usize {
+        self.len()
+    }
+
+    fn fit(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        _measure: Measure,
+    ) -> Self::Output {
+        self.pad(width, mode, symbol)
+    }
+}
+
+/// A two-pass padder of rows, where each column is as wide as its widest cell.
+#[derive(Debug, Clone, Default)]
+pub struct AutoFit {
+    measure: Measure,
+    max_width: Option<usize>,
+    alignment: Alignment,
+    symbol: Symbol,
+    /// The alignment of each column, where [`None`] falls back to the alignment of all
+    /// columns.
+    columns: Vec<Option<Alignment>>,
+}
+
+impl AutoFit {
+    /// Create a new [`AutoFit`] that measures characters and pads every column with the
+    /// default [`Alignment`] and [`Symbol`], without a maximum width.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how the width of text is measured.
+    pub fn with_measure(mut self, measure: Measure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Cap the width of every column, truncating wider cells according to the alignment
+    /// of their column.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Set the alignment of all columns without an alignment of their own.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the alignment of the column with the given index.
+    pub fn with_column_alignment(mut self, column: usize, alignment: Alignment) -> Self {
+        if self.columns.len() <= column {
+            self.columns.resize(column + 1, None);
+        }
+        self.columns[column] = Some(alignment);
+        self
+    }
+
+    /// Set the symbol to pad with.
+    pub fn with_symbol(mut self, symbol: Symbol) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Get the alignment of the column with the given index.
+    pub fn alignment(&self, column: usize) -> Alignment {
+        self.columns
+            .get(column)
+            .copied()
+            .flatten()
+            .unwrap_or(self.alignment)
+    }
+
+    /// Compute the width of each column as the width of its widest cell, capped to the
+    /// maximum width. Rows may have different numbers of cells.
+    pub fn widths<R: AsRef<[C]>, C: Fit>(&self, rows: &[R]) -> Vec<usize> {
+        let mut widths: Vec<usize> = Vec::new();
+        for row in rows {
+            let row: &[C] = row.as_ref();
+            if widths.len() < row.len() {
+                widths.resize(row.len(), 0);
+            }
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.width(self.measure));
+            }
+        }
+        match self.max_width {
+            Some(max_width) => widths.into_iter().map(|w| w.min(max_width)).collect(),
+            None => widths,
+        }
+    }
+
+    /// Pad every cell to the width of its column, where cells of columns without a width
+    /// are left as they are.
+    pub fn pad_to<R: AsRef<[C]>, C: Fit>(
+        &self,
+        rows: &[R],
+        widths: &[usize],
+    ) -> Vec<Vec<C::Output>> {
+        rows.iter()
+            .map(|row| {
+                row.as_ref()
+                    .iter()
+                    .enumerate()
+                    .map(|(column, cell)| {
+                        let width: usize = widths
+                            .get(column)
+                            .copied()
+                            .unwrap_or_else(|| cell.width(self.measure));
+                        cell.fit(width, self.alignment(column), self.symbol, self.measure)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Compute the width of each column and pad every cell to it, see [`AutoFit::widths`].
+    pub fn pad<R: AsRef<[C]>, C: Fit>(&self, rows: &[R]) -> Vec<Vec<C::Output>> {
+        self.pad_to(rows, &self.widths(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_text() {
+        let text: &str = "åäö";
+        assert_eq!(6, text.width(Measure::Bytes));
+        assert_eq!(3, text.width(Measure::Chars));
+        let fit = |width: usize, mode: Alignment, measure: Measure| {
+            text.fit(width, mode, Symbol::Hyphen, measure)
+        };
+
+        assert_eq!("åäö--", fit(5, Alignment::Left, Measure::Chars));
+        assert_eq!("åä", fit(2, Alignment::Left, Measure::Chars));
+        assert_eq!("äö", fit(2, Alignment::Right, Measure::Chars));
+        assert_eq!("ä", fit(1, Alignment::Center, Measure::Chars));
+        assert_eq!("åäö", fit(6, Alignment::Left, Measure::Bytes));
+        assert_eq!("å-", fit(3, Alignment::Left, Measure::Bytes));
+        assert_eq!("-ö", fit(3, Alignment::Right, Measure::Bytes));
+    }
+
+    #[test]
+    fn auto_fit_rows() {
+        let rows: Vec<Vec<&str>> = vec![vec!["a", "bb"], vec!["ccc"], vec![]];
+        let fit = AutoFit::new().with_symbol(Symbol::Dot);
+        assert_eq!(vec![3, 2], fit.widths(&rows));
+        let output: Vec<Vec<String>> = fit.pad(&rows);
+        assert_eq!(vec![vec!["..a", "bb"], vec!["ccc"], vec![]], output);
+
+        let output: Vec<Vec<String>> = fit.pad_to(&rows, &[2]);
+        assert_eq!(vec![vec![".a", "bb"], vec!["cc"], vec![]], output);
+
+        let fit = fit
+            .with_measure(Measure::Bytes)
+            .with_alignment(Alignment::Center);
+        let rows: [[&str; 2]; 2] = [["é", "x"], ["ab", "yyy"]];
+        assert_eq!(vec![2, 3], fit.widths(&rows));
+        assert_eq!(vec![vec!["é", ".x."], vec!["ab", "yyy"]], fit.pad(&rows));
+    }
+
+    #[test]
+    fn auto_fit_slices() {
+        let rows: Vec<Vec<&[u8]>> = vec![vec![b"12", b"x"], vec![b"3", b"yyyy"]];
+        let fit = AutoFit::new()
+            .with_symbol(Symbol::Zero)
+            .with_column_alignment(1, Alignment::Left)
+            .with_max_width(3);
+        assert_eq!(vec![2, 3], fit.widths(&rows));
+        assert_eq!(Alignment::Right, fit.alignment(0));
+        let output: Vec<Vec<Vec<u8>>> = fit.pad(&rows);
+        assert_eq!(
+            vec![
+                vec![b"12".to_vec(), b"x00".to_vec()],
+                vec![b"03".to_vec(), b"yyy".to_vec()]
+            ],
+            output
+        );
+    }
+}
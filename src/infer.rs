@@ -0,0 +1,339 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Inference of a fixed-width layout from sample records.
+//!
+//! Undocumented files rarely come with a spec, but the padding of their fields gives the
+//! layout away: columns that hold the fill symbol in every sample record separate the
+//! fields, the side of a field where the fill sits gives its alignment, and a run of
+//! digits directly followed by other characters in every record marks the end of a
+//! zero-padded number. [`infer_layout`] combines these clues into a [`Layout`], which
+//! is a starting point to be reviewed rather than a guaranteed spec, since two numbers
+//! or two texts without any padding between them cannot be told apart.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let lines = ["007alice   00125Y", "012bob     10000N", "100eve     00007Y"];
+//! let layout: Layout = infer_layout(&lines).unwrap();
+//!
+//! let expected = Layout::new(vec![
+//!     Field::new("field1", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+//!     Field::new("field2", 8, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("field3", 5, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+//!     Field::new("field4", 1, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//! ]);
+//! assert_eq!(expected, layout);
+//! ```
+//!
+
+use std::ops::Range;
+
+use crate::layout::{Field, FieldKind, Layout};
+use crate::{Alignment, PadderError, Symbol};
+
+/// The symbols that are considered as the fill of the text fields of a file, where the
+/// most frequent one in the sample wins.
+const FILLS: [Symbol; 11] = [
+    Symbol::Whitespace,
+    Symbol::Hyphen,
+    Symbol::Underscore,
+    Symbol::Dot,
+    Symbol::Comma,
+    Symbol::Colon,
+    Symbol::Semicolon,
+    Symbol::Hashtag,
+    Symbol::Backslash,
+    Symbol::Forwardslash,
+    Symbol::Asterisk,
+];
+
+/// The sample records, where records shorter than the widest one are treated as if
+/// their trailing padding had been trimmed.
+struct Sample<'a> {
+    lines: Vec<&'a [u8]>,
+    fill: u8,
+}
+
+impl Sample<'_> {
+    /// Get the byte at the column of the line.
+    fn at(&self, line: &[u8], column: usize) -> u8 {
+        line.get(column).copied().unwrap_or(self.fill)
+    }
+
+    /// Check whether the column holds the fill in every line.
+    fn blank(&self, column: usize) -> bool {
+        self.lines.iter().all(|l| self.at(l, column) == self.fill)
+    }
+
+    /// Check whether every line switches between a digit and another character that is
+    /// not the fill, right before the column.
+    fn split(&self, column: usize) -> bool {
+        self.lines.iter().all(|l| {
+            let (a, b) = (self.at(l, column - 1), self.at(l, column));
+            a != self.fill && b != self.fill && a.is_ascii_digit() != b.is_ascii_digit()
+        })
+    }
+
+    /// Get the alignment of the columns from the side where the fill sits, or [`None`]
+    /// if the columns are never padded.
+    fn alignment(&self, range: &Range<usize>) -> Option<Alignment> {
+        let (mut leading, mut trailing) = (false, false);
+        for line in &self.lines {
+            let first: u8 = self.at(line, range.start);
+            let last: u8 = self.at(line, range.end - 1);
+            if range.clone().all(|c| self.at(line, c) == self.fill) {
+                continue;
+            }
+            leading |= first == self.fill;
+            trailing |= last == self.fill;
+        }
+        match (leading, trailing) {
+            (false, false) => None,
+            (true, false) => Some(Alignment::Right),
+            (false, true) => Some(Alignment::Left),
+            (true, true) => Some(Alignment::Center),
+        }
+    }
+
+    /// Check whether the columns only hold digits, apart from the fill.
+    fn numeric(&self, range: &Range<usize>) -> bool {
+        let bytes = || {
+            self.lines
+                .iter()
+                .flat_map(|l| range.clone().map(|c| self.at(l, c)))
+        };
+        bytes().all(|b| b == self.fill || b.is_ascii_digit()) && bytes().any(|b| b != self.fill)
+    }
+
+    /// Create the field of the columns.
+    fn field(&self, index: usize, range: Range<usize>) -> Field {
+        let alignment: Option<Alignment> = self.alignment(&range);
+        let name: String = format!("field{}", index + 1);
+        let width: usize = range.end - range.start;
+        if !self.numeric(&range) {
+            let alignment: Alignment = alignment.unwrap_or(Alignment::Left);
+            return Field::new(&name, width, alignment, self.fill_symbol(), FieldKind::Text);
+        }
+
+        // Numbers that are never padded with the fill of the file are padded with zeros.
+        let symbol: Symbol = match alignment {
+            None => Symbol::Zero,
+            Some(_) => self.fill_symbol(),
+        };
+        let alignment: Alignment = alignment.unwrap_or(Alignment::Right);
+        Field::new(&name, width, alignment, symbol, FieldKind::Numeric)
+    }
+
+    /// Get the symbol of the fill of the file.
+    fn fill_symbol(&self) -> Symbol {
+        FILLS
+            .into_iter()
+            .find(|&s| u8::from(s) == self.fill)
+            .unwrap_or_default()
+    }
+}
+
+/// Infer the layout of fixed-width records from sample lines, naming the fields
+/// `field1`, `field2` and so on.
+///
+/// The fill of the text fields is the most frequent padding symbol of the sample. Each
+/// run of columns that are not blank in every line becomes a field, split where every
+/// line switches between digits and other characters. The blank columns between two
+/// fields are given to the field that is padded on that side, or become a field of
+/// their own if neither is. Fields with only digits are numeric, padded with zeros if
+/// the fill of the file never occurs in them.
+///
+/// Returns an error if there are no lines, ignoring empty lines and line endings.
+pub fn infer_layout<S: AsRef<[u8]>>(lines: &[S]) -> Result<Layout, PadderError> {
+    let lines: Vec<&[u8]> = lines
+        .iter()
+        .map(|l| l.as_ref())
+        .map(|l| l.strip_suffix(b"\n").unwrap_or(l))
+        .map(|l| l.strip_suffix(b"\r").unwrap_or(l))
+        .filter(|l| !l.is_empty())
+        .collect();
+    let width: usize = lines.iter().map(|l| l.len()).max().ok_or_else(|| {
+        PadderError::InvalidValue("no sample lines to infer a layout from".to_string())
+    })?;
+
+    let count = |fill: u8| {
+        lines
+            .iter()
+            .flat_map(|l| l.iter())
+            .filter(|&&b| b == fill)
+            .count()
+    };
+    let fill: u8 = FILLS
+        .into_iter()
+        .map(u8::from)
+        .rev()
+        .max_by_key(|&fill| count(fill))
+        .unwrap_or(b' ');
+    let sample = Sample { lines, fill };
+
+    let mut blocks: Vec<Range<usize>> = Vec::new();
+    let mut column: usize = 0;
+    while column < width {
+        if sample.blank(column) {
+            column += 1;
+            continue;
+        }
+        let start: usize = column;
+        column += 1;
+        while column < width && !sample.blank(column) && !sample.split(column) {
+            column += 1;
+        }
+        blocks.push(start..column);
+    }
+    if blocks.is_empty() {
+        blocks.push(0..width);
+    }
+
+    // The blank columns around a block belong to the block if it is padded on that
+    // side, or never padded at all, and otherwise become a field of their own.
+    let alignments: Vec<Option<Alignment>> = blocks.iter().map(|b| sample.alignment(b)).collect();
+    let mut ranges: Vec<Range<usize>> = blocks.clone();
+    if blocks[0].start > 0 {
+        match alignments[0] {
+            Some(Alignment::Right) | None => ranges[0].start = 0,
+            Some(_) => ranges.push(0..blocks[0].start),
+        }
+    }
+    for index in 0..blocks.len() {
+        let end: usize = blocks.get(index + 1).map_or(width, |b| b.start);
+        if blocks[index].end == end {
+            continue;
+        }
+        let next: Option<Option<Alignment>> = alignments.get(index + 1).copied();
+        match (alignments[index], next) {
+            (Some(Alignment::Left), _) => ranges[index].end = end,
+            (_, Some(Some(Alignment::Right))) => ranges[index + 1].start = blocks[index].end,
+            (None, _) => ranges[index].end = end,
+            (_, Some(None)) => ranges[index + 1].start = blocks[index].end,
+            _ => ranges.push(blocks[index].end..end),
+        }
+    }
+    ranges.sort_by_key(|r| r.start);
+
+    let fields: Vec<Field> = ranges
+        .into_iter()
+        .enumerate()
+        .map(|(index, range)| sample.field(index, range))
+        .collect();
+    let layout = Layout::new(fields);
+    layout.validate()?;
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(
+        name: &str,
+        width: usize,
+        alignment: Alignment,
+        symbol: Symbol,
+        kind: FieldKind,
+    ) -> Field {
+        Field::new(name, width, alignment, symbol, kind)
+    }
+
+    #[test]
+    fn infer_gaps() {
+        let lines = [" 12  ab \r\n", "345  c\n", "\n"];
+        let layout: Layout = infer_layout(&lines).unwrap();
+        let expected = Layout::new(vec![
+            field(
+                "field1",
+                3,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Numeric,
+            ),
+            field(
+                "field2",
+                2,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            field(
+                "field3",
+                3,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+        ]);
+        assert_eq!(expected, layout);
+        assert_eq!(vec!["345", "", "c"], layout.parse_str("345  c  ").unwrap());
+    }
+
+    #[test]
+    fn infer_fill_symbol() {
+        let lines = ["ab____12", "abc____7", "a____100"];
+        let layout: Layout = infer_layout(&lines).unwrap();
+        let expected = Layout::new(vec![
+            field(
+                "field1",
+                5,
+                Alignment::Left,
+                Symbol::Underscore,
+                FieldKind::Text,
+            ),
+            field(
+                "field2",
+                3,
+                Alignment::Right,
+                Symbol::Underscore,
+                FieldKind::Numeric,
+            ),
+        ]);
+        assert_eq!(expected, layout);
+    }
+
+    #[test]
+    fn infer_errors() {
+        let lines: [&str; 2] = ["", "\r\n"];
+        let output = infer_layout(&lines);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+
+        let lines = ["    "];
+        let layout: Layout = infer_layout(&lines).unwrap();
+        let expected = Layout::new(vec![field(
+            "field1",
+            4,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )]);
+        assert_eq!(expected, layout);
+    }
+}
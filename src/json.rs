@@ -0,0 +1,179 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Conversion from fixed-width files to JSON Lines.
+//!
+//! [`fixed_width_to_json_lines`] writes the rows read by a [`FixedWidthReader`] as JSON
+//! objects, one per line, keyed by the names of the fields. The padding is stripped and
+//! the numbers are decoded, keeping the implied decimals of their fields.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("name", 6, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//! let reader = FixedWidthReader::new("b,c   000300\n".as_bytes(), layout);
+//! let mut output: Vec<u8> = Vec::new();
+//! fixed_width_to_json_lines(reader, &mut output).unwrap();
+//! assert_eq!(b"{\"name\":\"b,c\",\"amount\":3.00}\n", output.as_slice());
+//! ```
+//!
+
+use serde::ser::{Error, SerializeMap};
+use serde::{Serialize, Serializer};
+use serde_json::value::RawValue;
+
+use std::io;
+
+use crate::reader::{FixedWidthReader, Row};
+use crate::value::Value;
+use crate::PadderError;
+
+/// A row serialized as a JSON object keyed by the names of its fields.
+struct JsonRow<'a>(&'a Row);
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = self.0.layout().fields();
+        let mut map = serializer.serialize_map(Some(fields.len()))?;
+        for (field, value) in fields.iter().zip(self.0.values()) {
+            map.serialize_entry(&field.name, &JsonValue(value))?;
+        }
+        map.end()
+    }
+}
+
+/// A value serialized as JSON, where decimals keep their implied decimals and date
+/// times and bytes are written as strings.
+struct JsonValue<'a, 'b>(&'a Value<'b>);
+
+impl Serialize for JsonValue<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Null => serializer.serialize_none(),
+            Value::Text(text) => serializer.serialize_str(text),
+            Value::Integer(value) => serializer.serialize_i64(*value),
+            Value::Decimal(..) => RawValue::from_string(self.0.to_string())
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            Value::Float(value) => serializer.serialize_f64(*value),
+            Value::Boolean(value) => serializer.serialize_bool(*value),
+            Value::Bytes(_) | Value::DateTime(_) => serializer.collect_str(self.0),
+        }
+    }
+}
+
+/// Write the rows of the reader as JSON objects, one per line, to the output, returning
+/// the number of rows written.
+///
+/// The keys of each object are the names of the fields of the layout of the row, and
+/// floats that are not finite are written as `null`.
+pub fn fixed_width_to_json_lines<R: io::BufRead, W: io::Write>(
+    reader: FixedWidthReader<R>,
+    mut output: W,
+) -> Result<u64, PadderError> {
+    let mut count: u64 = 0;
+    let mut line: Vec<u8> = Vec::new();
+    for row in reader {
+        let row: Row = row?;
+
+        line.clear();
+        serde_json::to_writer(&mut line, &JsonRow(&row))
+            .map_err(|e| PadderError::InvalidValue(e.to_string()))?;
+        line.push(b'\n');
+
+        output.write_all(&line)?;
+        count += 1;
+    }
+
+    output.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind, Layout};
+    use crate::{Alignment, Symbol};
+
+    #[test]
+    fn fixed_width_to_json() {
+        let layout = Layout::new(vec![
+            Field::new("id", 2, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                5,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "rate",
+                4,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(3),
+        ]);
+        let input = "07a\"b  1250\n12c\td\u{1} 0005\n";
+        let reader = FixedWidthReader::new(input.as_bytes(), layout);
+        let mut output: Vec<u8> = Vec::new();
+        assert_eq!(2, fixed_width_to_json_lines(reader, &mut output).unwrap());
+        assert_eq!(
+            concat!(
+                "{\"id\":7,\"name\":\"a\\\"b\",\"rate\":1.250}\n",
+                "{\"id\":12,\"name\":\"c\\td\\u0001\",\"rate\":0.005}\n",
+            ),
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn fixed_width_to_json_errors() {
+        let layout = Layout::new(vec![Field::new(
+            "id",
+            3,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )]);
+        let reader = FixedWidthReader::new("001\n0x2\n".as_bytes(), layout);
+        let output = fixed_width_to_json_lines(reader, Vec::new());
+        assert!(matches!(
+            output,
+            Err(PadderError::Record {
+                line: 2,
+                offset: 4,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,1261 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Fixed-width record layouts.
+//!
+//! A [`Layout`] describes a record as an ordered list of [`Field`]s, each with a name,
+//! a width, and the [`Alignment`] and [`Symbol`] used to pad its value. The [`FieldKind`]
+//! of a field decides how its value is encoded, from plain padded text to zoned, packed
+//! and binary numbers. The same layout parses records back into their fields, which
+//! makes reading and writing symmetric.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("id", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+//!     Field::new("name", 8, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 7, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(layout.width());
+//! layout
+//!     .write(&[Value::from(42), Value::from("hello"), Value::from("13.37")], &mut buffer)
+//!     .unwrap();
+//! assert_eq!(b"000042hello   0001337", buffer.as_slice());
+//!
+//! let fields: Vec<&str> = layout.parse_str("000042hello   0001337").unwrap();
+//! assert_eq!(vec!["42", "hello", "1337"], fields);
+//!
+//! let values: Vec<Value> = layout.decode(&buffer).unwrap();
+//! assert_eq!(Value::Decimal(1337, 2), values[2]);
+//! ```
+//!
+//! With the `serde` feature enabled, layouts can also be loaded from spec documents, see
+//! [`Layout::from_path`]. Only the name and width of each field are required:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use padder::*;
+//!
+//! let layout = Layout::from_json(r#"{
+//!     "fields": [
+//!         { "name": "id", "width": 6, "symbol": "Zero", "kind": "Numeric" },
+//!         { "name": "name", "width": 8, "alignment": "Left" }
+//!     ]
+//! }"#).unwrap();
+//! assert_eq!(14, layout.width());
+//! # }
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::borrow::Cow;
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+use crate::binary::{decode_binary, encode_binary, Endianness};
+use crate::codes::BoolCodes;
+use crate::datetime::{DateTime, DateTimeFormat};
+use crate::packed::{decode_packed, encode_packed};
+use crate::value::Value;
+use crate::zoned::{
+    decode_overpunch, decode_zoned, digits, encode_overpunch, encode_zoned, Encoding,
+};
+use crate::{pad_and_push_to_buffer, Alignment, PadderError, Symbol, Unpad};
+
+/// Exhaustive enum for the kinds of fields, deciding how their values are encoded.
+///
+/// The numeric kinds hold integers with the number of implied decimals given by the
+/// scale of the field.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum FieldKind {
+    /// Text padded with the alignment and symbol of the field.
+    #[default]
+    Text,
+    /// Unsigned digits padded with the alignment and symbol of the field.
+    Numeric,
+    /// Unsigned zoned decimal digits filling the whole width, see [`encode_zoned`].
+    Zoned(Encoding),
+    /// Zoned decimal digits with an overpunched sign, see [`encode_overpunch`].
+    Signed(Encoding),
+    /// Packed decimal where the width is the byte length, see [`encode_packed`].
+    Packed { signed: bool },
+    /// Binary integer where the width is the byte length, see [`encode_binary`].
+    Binary {
+        signed: bool,
+        endianness: Endianness,
+    },
+    /// Date and time formatted with the pattern of the format.
+    DateTime(DateTimeFormat),
+    /// Boolean encoded as a code.
+    Boolean(BoolCodes),
+}
+
+/// Exhaustive enum for the ways to handle text that is wider than its field.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Overflow {
+    /// Fail with an error.
+    #[default]
+    Error,
+    /// Truncate the text according to the alignment, see [`Source::slice_to_fit`](crate::Source::slice_to_fit).
+    Truncate,
+}
+
+/// A single field of a fixed-width record.
+///
+/// When loaded from a spec, every property except the name and width is optional and
+/// falls back to its default.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Field {
+    pub name: String,
+    pub width: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alignment: Alignment,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub symbol: Symbol,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: FieldKind,
+    /// The number of implied decimals of numeric fields.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scale: u32,
+    /// How to handle text that is wider than the field.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overflow: Overflow,
+}
+
+impl Field {
+    /// Create a new [`Field`] without implied decimals that fails on overflow.
+    pub fn new(
+        name: &str,
+        width: usize,
+        alignment: Alignment,
+        symbol: Symbol,
+        kind: FieldKind,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            width,
+            alignment,
+            symbol,
+            kind,
+            scale: 0,
+            overflow: Overflow::default(),
+        }
+    }
+
+    /// Set the number of implied decimals of the field.
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set how to handle text that is wider than the field.
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Pad text to the width of the field and push it to the buffer.
+    fn write_text(&self, text: &[u8], buffer: &mut Vec<u8>) -> Result<(), PadderError> {
+        if text.len() > self.width && self.overflow == Overflow::Error {
+            return Err(PadderError::Overflow {
+                width: self.width,
+                required: text.len(),
+            });
+        }
+        pad_and_push_to_buffer(text, self.width, self.alignment, self.symbol, buffer);
+        Ok(())
+    }
+
+    /// Fill the whole field with the symbol.
+    fn write_null(&self, buffer: &mut Vec<u8>) {
+        let empty: &[u8] = &[];
+        pad_and_push_to_buffer(empty, self.width, self.alignment, self.symbol, buffer);
+    }
+
+    /// Encode the value according to the kind of the field and push it to the buffer.
+    ///
+    /// A missing value is written as a field consisting only of the fill symbol, except
+    /// for binary fields where it is written as zero.
+    pub fn write(&self, value: &Value, buffer: &mut Vec<u8>) -> Result<(), PadderError> {
+        match &self.kind {
+            FieldKind::Text => match value {
+                Value::Null => {
+                    self.write_null(buffer);
+                    Ok(())
+                }
+                Value::Text(text) => self.write_text(text.as_bytes(), buffer),
+                Value::Bytes(bytes) => self.write_text(bytes, buffer),
+                _ => self.write_text(value.to_string().as_bytes(), buffer),
+            },
+            FieldKind::Numeric => match value.to_scaled(self.scale)? {
+                None => {
+                    self.write_null(buffer);
+                    Ok(())
+                }
+                Some(number) if number < 0 => Err(PadderError::OutOfRange),
+                Some(number) => {
+                    let mut scratch = [0u8; 20];
+                    let digits: &[u8] = digits(number as u64, &mut scratch);
+                    if digits.len() > self.width {
+                        return Err(PadderError::Overflow {
+                            width: self.width,
+                            required: digits.len(),
+                        });
+                    }
+                    self.write_text(digits, buffer)
+                }
+            },
+            FieldKind::Zoned(encoding) => match value.to_scaled(self.scale)? {
+                None => {
+                    self.write_null(buffer);
+                    Ok(())
+                }
+                Some(number) if number < 0 => Err(PadderError::OutOfRange),
+                Some(number) => encode_zoned(number as u64, self.width, *encoding, buffer),
+            },
+            FieldKind::Signed(encoding) => match value.to_scaled(self.scale)? {
+                None => {
+                    self.write_null(buffer);
+                    Ok(())
+                }
+                Some(number) => encode_overpunch(number, self.width, *encoding, buffer),
+            },
+            FieldKind::Packed { signed } => match value.to_scaled(self.scale)? {
+                None => {
+                    self.write_null(buffer);
+                    Ok(())
+                }
+                Some(number) => encode_packed(number, self.width, *signed, buffer),
+            },
+            FieldKind::Binary { signed, endianness } => {
+                let number: i64 = value.to_scaled(self.scale)?.unwrap_or(0);
+                encode_binary(number as i128, self.width, *signed, *endianness, buffer)
+            }
+            FieldKind::DateTime(format) => {
+                if format.width() != self.width {
+                    return Err(PadderError::InvalidLength(format.width()));
+                }
+                let datetime: Option<DateTime> = match value {
+                    Value::Null => None,
+                    Value::DateTime(datetime) => Some(*datetime),
+                    Value::Text(text) if text.trim().is_empty() => None,
+                    Value::Text(text) => Some(match text.parse::<DateTime>() {
+                        Ok(datetime) => datetime,
+                        Err(_) => format
+                            .parse(text.as_bytes(), self.symbol)?
+                            .ok_or(PadderError::InvalidDateTime)?,
+                    }),
+                    _ => return Err(PadderError::InvalidDateTime),
+                };
+                format.write(datetime.as_ref(), self.symbol, buffer)
+            }
+            FieldKind::Boolean(codes) => {
+                let boolean: Option<bool> = match value {
+                    Value::Null => None,
+                    Value::Boolean(boolean) => Some(*boolean),
+                    Value::Text(text) => match text.trim() {
+                        "" => None,
+                        "true" => Some(true),
+                        "false" => Some(false),
+                        code if code == codes.code(true) => Some(true),
+                        code if code == codes.code(false) => Some(false),
+                        code => return Err(PadderError::UnknownCode(code.to_string())),
+                    },
+                    _ => return Err(PadderError::UnknownCode(value.to_string())),
+                };
+                codes.write(boolean, self.width, self.alignment, self.symbol, buffer)
+            }
+        }
+    }
+
+    /// Strip the padding from a raw field according to the alignment and symbol of the
+    /// field, see [`Unpad`].
+    ///
+    /// The fields that are decoded from their full width, that is, zoned, signed, packed,
+    /// binary and date and time fields, are returned as is.
+    pub fn strip<'a>(&self, field: &'a [u8]) -> &'a [u8] {
+        match self.kind {
+            FieldKind::Text | FieldKind::Numeric | FieldKind::Boolean(_) => {
+                field.unpad(self.alignment, self.symbol)
+            }
+            _ => field,
+        }
+    }
+
+    /// Get the value of an unscaled integer with the scale of the field.
+    fn number<'a>(&self, value: i64) -> Value<'a> {
+        match self.scale {
+            0 => Value::Integer(value),
+            scale => Value::Decimal(value, scale),
+        }
+    }
+
+    /// Decode a raw field according to the kind of the field.
+    ///
+    /// Text is borrowed from the field, and a field consisting only of the fill symbol is
+    /// decoded as a missing value, except for text which is decoded as empty text and
+    /// numeric fields filled with [`Symbol::Zero`] which are decoded as zero.
+    pub fn decode<'a>(&self, field: &'a [u8]) -> Result<Value<'a>, PadderError> {
+        let fill: u8 = self.symbol.into();
+        let blank: bool = field.iter().all(|&b| b == fill);
+        if field.is_empty() && self.kind != FieldKind::Text {
+            return Ok(Value::Null);
+        }
+
+        match &self.kind {
+            FieldKind::Text => {
+                let text: &[u8] = self.strip(field);
+                Ok(match std::str::from_utf8(text) {
+                    Ok(text) => Value::Text(Cow::Borrowed(text)),
+                    Err(_) => Value::Bytes(Cow::Borrowed(text)),
+                })
+            }
+            FieldKind::Numeric => match self.strip(field) {
+                [] if self.symbol == Symbol::Zero => Ok(self.number(0)),
+                [] => Ok(Value::Null),
+                digits => {
+                    let value: u64 = decode_zoned(digits, Encoding::Ascii)?;
+                    let value: i64 = i64::try_from(value).map_err(|_| PadderError::OutOfRange)?;
+                    Ok(self.number(value))
+                }
+            },
+            FieldKind::Zoned(_) | FieldKind::Signed(_) if blank && self.symbol != Symbol::Zero => {
+                Ok(Value::Null)
+            }
+            FieldKind::Packed { .. } if blank => Ok(Value::Null),
+            FieldKind::Zoned(encoding) => {
+                let value: u64 = decode_zoned(field, *encoding)?;
+                let value: i64 = i64::try_from(value).map_err(|_| PadderError::OutOfRange)?;
+                Ok(self.number(value))
+            }
+            FieldKind::Signed(encoding) => Ok(self.number(decode_overpunch(field, *encoding)?)),
+            FieldKind::Packed { .. } => Ok(self.number(decode_packed(field)?)),
+            FieldKind::Binary { signed, endianness } => {
+                let value: i128 = decode_binary(field, *signed, *endianness)?;
+                let value: i64 = i64::try_from(value).map_err(|_| PadderError::OutOfRange)?;
+                Ok(self.number(value))
+            }
+            FieldKind::DateTime(format) => Ok(format
+                .parse(field, self.symbol)?
+                .map_or(Value::Null, Value::DateTime)),
+            FieldKind::Boolean(codes) => Ok(codes
+                .parse(field, self.alignment, self.symbol)?
+                .map_or(Value::Null, Value::Boolean)),
+        }
+    }
+}
+
+/// An ordered list of fields describing a fixed-width record.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Layout {
+    fields: Vec<Field>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    lenient_trailing: bool,
+}
+
+impl Layout {
+    /// Create a new [`Layout`] from the ordered list of fields.
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self {
+            fields,
+            lenient_trailing: false,
+        }
+    }
+
+    /// Set whether records shorter than the layout are accepted when parsing, in which
+    /// case the trailing fields are cut short or empty.
+    ///
+    /// This is useful for files where trailing padding has been trimmed from records.
+    pub fn with_lenient_trailing(mut self, lenient_trailing: bool) -> Self {
+        self.lenient_trailing = lenient_trailing;
+        self
+    }
+
+    /// Get the fields of the layout.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Get the field with the given name, if it exists.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Get the index of the field with the given name, if it exists.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|f| f.name == name)
+    }
+
+    /// Get the total width of a record.
+    pub fn width(&self) -> usize {
+        self.fields.iter().map(|f| f.width).sum()
+    }
+
+    /// Get the offset of each field from the start of the record.
+    pub fn offsets(&self) -> Vec<usize> {
+        self.fields
+            .iter()
+            .scan(0, |offset, f| {
+                let start: usize = *offset;
+                *offset += f.width;
+                Some(start)
+            })
+            .collect()
+    }
+
+    /// Verify that the layout can be used to write and parse records.
+    ///
+    /// A layout needs at least one field, every field needs a positive width, binary
+    /// fields need a width of 1, 2, 4 or 8 bytes, date and time fields need the width of
+    /// their format, and the scale can be at most 18, the most decimals an i64 holds.
+    /// Numeric and signed fields filled with [`Symbol::Zero`] need to be right-aligned,
+    /// since trailing zeros would change their value. Layouts loaded from specs are always
+    /// validated.
+    pub fn validate(&self) -> Result<(), PadderError> {
+        if self.fields.is_empty() {
+            return Err(PadderError::InvalidLayout(
+                "layout has no fields".to_string(),
+            ));
+        }
+
+        for field in &self.fields {
+            let valid: bool = match &field.kind {
+                _ if field.width == 0 => false,
+                FieldKind::Binary { .. } => matches!(field.width, 1 | 2 | 4 | 8),
+                FieldKind::DateTime(format) => format.width() == field.width,
+                _ => true,
+            };
+            if !valid {
+                return Err(PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(PadderError::InvalidLength(field.width)),
+                });
+            }
+            if field.scale > 18 {
+                return Err(PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(PadderError::InvalidValue(format!(
+                        "scale {} is more than 18 decimals",
+                        field.scale
+                    ))),
+                });
+            }
+            let numeric: bool = matches!(field.kind, FieldKind::Numeric | FieldKind::Signed(_));
+            if numeric && field.symbol == Symbol::Zero && field.alignment != Alignment::Right {
+                return Err(PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(PadderError::InvalidValue(
+                        "numbers filled with zeros need right alignment".to_string(),
+                    )),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load and validate a layout from a JSON spec.
+    #[cfg(feature = "serde")]
+    pub fn from_json(spec: &str) -> Result<Self, PadderError> {
+        let layout: Self =
+            serde_json::from_str(spec).map_err(|e| PadderError::InvalidLayout(e.to_string()))?;
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// Load and validate a layout from a TOML spec.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(spec: &str) -> Result<Self, PadderError> {
+        let layout: Self =
+            toml::from_str(spec).map_err(|e| PadderError::InvalidLayout(e.to_string()))?;
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// Load and validate a layout from a YAML spec.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(spec: &str) -> Result<Self, PadderError> {
+        let layout: Self =
+            serde_yaml::from_str(spec).map_err(|e| PadderError::InvalidLayout(e.to_string()))?;
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// Write the layout as a JSON spec.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, PadderError> {
+        serde_json::to_string_pretty(self).map_err(|e| PadderError::InvalidLayout(e.to_string()))
+    }
+
+    /// Write the layout as a TOML spec.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, PadderError> {
+        toml::to_string_pretty(self).map_err(|e| PadderError::InvalidLayout(e.to_string()))
+    }
+
+    /// Write the layout as a YAML spec.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, PadderError> {
+        serde_yaml::to_string(self).map_err(|e| PadderError::InvalidLayout(e.to_string()))
+    }
+
+    /// Load and validate a layout from a spec file, where the format of the spec is
+    /// decided by the file extension: `.json`, `.toml`, or `.yaml` and `.yml`.
+    ///
+    /// TOML and YAML specs require the `toml` and `yaml` features respectively.
+    #[cfg(feature = "serde")]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, PadderError> {
+        let path: &Path = path.as_ref();
+        let extension: Option<String> = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+
+        let load: fn(&str) -> Result<Self, PadderError> = match extension.as_deref() {
+            Some("json") => Self::from_json,
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml,
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Self::from_yaml,
+            _ => {
+                return Err(PadderError::InvalidLayout(format!(
+                    "unsupported spec format '{}'",
+                    path.display()
+                )))
+            }
+        };
+
+        load(&fs::read_to_string(path)?)
+    }
+
+    /// Write one value per field as a record and push it to the buffer.
+    ///
+    /// If any value fails to be written, or the record does not end up with the width
+    /// of the layout, an error is returned and the buffer is left untouched.
+    pub fn write(&self, values: &[Value], buffer: &mut Vec<u8>) -> Result<(), PadderError> {
+        if values.len() != self.fields.len() {
+            return Err(PadderError::FieldCount {
+                expected: self.fields.len(),
+                found: values.len(),
+            });
+        }
+
+        let start: usize = buffer.len();
+        for (field, value) in self.fields.iter().zip(values.iter()) {
+            if let Err(error) = field.write(value, buffer) {
+                buffer.truncate(start);
+                return Err(PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(error),
+                });
+            }
+        }
+
+        let found: usize = buffer.len() - start;
+        if found != self.width() {
+            buffer.truncate(start);
+            return Err(PadderError::RecordLength {
+                expected: self.width(),
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Slice the record into its raw fields, without stripping any padding.
+    ///
+    /// Records longer than the layout are always an error, while records shorter than
+    /// the layout are only accepted if the layout has lenient trailing fields.
+    pub fn split<'a>(&self, record: &'a [u8]) -> Result<Vec<&'a [u8]>, PadderError> {
+        let width: usize = self.width();
+        if record.len() > width || (record.len() < width && !self.lenient_trailing) {
+            return Err(PadderError::RecordLength {
+                expected: width,
+                found: record.len(),
+            });
+        }
+
+        let mut offset: usize = 0;
+        Ok(self
+            .fields
+            .iter()
+            .map(|f| {
+                let start: usize = offset.min(record.len());
+                offset += f.width;
+                &record[start..offset.min(record.len())]
+            })
+            .collect())
+    }
+
+    /// Parse the record into one borrowed slice per field, with the padding stripped,
+    /// see [`Field::strip`].
+    pub fn parse<'a>(&self, record: &'a [u8]) -> Result<Vec<&'a [u8]>, PadderError> {
+        Ok(self
+            .split(record)?
+            .into_iter()
+            .zip(self.fields.iter())
+            .map(|(raw, f)| f.strip(raw))
+            .collect())
+    }
+
+    /// Parse the record into one borrowed string slice per field, with the padding
+    /// stripped, see [`Field::strip`].
+    pub fn parse_str<'a>(&self, record: &'a str) -> Result<Vec<&'a str>, PadderError> {
+        self.parse(record.as_bytes())?
+            .into_iter()
+            .zip(self.fields.iter())
+            .map(|(raw, f)| {
+                std::str::from_utf8(raw).map_err(|_| PadderError::Field {
+                    name: f.name.clone(),
+                    error: Box::new(PadderError::InvalidValue(
+                        "field is not valid UTF-8".to_string(),
+                    )),
+                })
+            })
+            .collect()
+    }
+
+    /// Decode the record into one value per field, see [`Field::decode`].
+    pub fn decode<'a>(&self, record: &'a [u8]) -> Result<Vec<Value<'a>>, PadderError> {
+        self.split(record)?
+            .into_iter()
+            .zip(self.fields.iter())
+            .map(|(raw, f)| {
+                f.decode(raw).map_err(|error| PadderError::Field {
+                    name: f.name.clone(),
+                    error: Box::new(error),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(name: &str, width: usize) -> Field {
+        Field::new(
+            name,
+            width,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )
+    }
+
+    fn numeric(name: &str, width: usize) -> Field {
+        Field::new(
+            name,
+            width,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )
+    }
+
+    #[test]
+    fn layout_width_and_offsets() {
+        let layout = Layout::new(vec![text("a", 3), numeric("b", 5), text("c", 2)]);
+        assert_eq!(10, layout.width());
+        assert_eq!(vec![0, 3, 8], layout.offsets());
+        assert_eq!(Some(1), layout.position("b"));
+        assert_eq!(Some(2), layout.field("c").map(|f| f.width));
+        assert_eq!(None, layout.field("d"));
+    }
+
+    #[test]
+    fn write_text_and_numeric() {
+        let layout = Layout::new(vec![text("name", 6), numeric("count", 4)]);
+        let mut buffer: Vec<u8> = Vec::new();
+        layout
+            .write(&[Value::from("abc"), Value::from(12)], &mut buffer)
+            .unwrap();
+        layout
+            .write(&[Value::Null, Value::from("7")], &mut buffer)
+            .unwrap();
+        assert_eq!(b"abc   0012      0007", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_text_overflow() {
+        let layout = Layout::new(vec![text("name", 3)]);
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = layout.write(&[Value::from("abcd")], &mut buffer);
+        let expected = Err(PadderError::Field {
+            name: "name".to_string(),
+            error: Box::new(PadderError::Overflow {
+                width: 3,
+                required: 4,
+            }),
+        });
+        assert_eq!(expected, output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_text_truncate() {
+        let field = Field::new("name", 3, Alignment::Right, Symbol::Hyphen, FieldKind::Text)
+            .with_overflow(Overflow::Truncate);
+        let layout = Layout::new(vec![field]);
+        let mut buffer: Vec<u8> = Vec::new();
+        layout.write(&[Value::from("kappa")], &mut buffer).unwrap();
+        assert_eq!(b"ppa", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_numeric_overflow_never_truncates() {
+        let field = numeric("count", 2).with_overflow(Overflow::Truncate);
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = field.write(&Value::from(123), &mut buffer);
+        let expected = Err(PadderError::Overflow {
+            width: 2,
+            required: 3,
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn write_numeric_scale() {
+        let field = numeric("amount", 8).with_scale(2);
+        let mut buffer: Vec<u8> = Vec::new();
+        field.write(&Value::from(12.5), &mut buffer).unwrap();
+        field.write(&Value::Decimal(1, 1), &mut buffer).unwrap();
+        field.write(&Value::from(3), &mut buffer).unwrap();
+        assert_eq!(b"000012500000001000000300", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_numeric_negative() {
+        let field = numeric("count", 4);
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = field.write(&Value::from(-1), &mut buffer);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+    }
+
+    #[test]
+    fn write_mixed_record() {
+        let layout = Layout::new(vec![
+            Field::new(
+                "signed",
+                4,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Signed(Encoding::Ascii),
+            )
+            .with_scale(1),
+            Field::new(
+                "packed",
+                2,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Packed { signed: true },
+            ),
+            Field::new(
+                "binary",
+                2,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Binary {
+                    signed: false,
+                    endianness: Endianness::Little,
+                },
+            ),
+            Field::new(
+                "date",
+                8,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+            Field::new(
+                "flag",
+                1,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+        ]);
+
+        let mut buffer: Vec<u8> = Vec::with_capacity(layout.width());
+        let values = [
+            Value::from("-1.5"),
+            Value::from(-12),
+            Value::from(258),
+            Value::from("2024-05-10"),
+            Value::from(true),
+        ];
+        layout.write(&values, &mut buffer).unwrap();
+        assert_eq!(b"001N\x01\x2D\x02\x0120240510Y", buffer.as_slice());
+
+        buffer.clear();
+        let values = [
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+            Value::Null,
+        ];
+        layout.write(&values, &mut buffer).unwrap();
+        assert_eq!(b"000000\x00\x00         ", buffer.as_slice());
+    }
+
+    #[test]
+    fn write_field_count() {
+        let layout = Layout::new(vec![text("a", 1), text("b", 1)]);
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = layout.write(&[Value::Null], &mut buffer);
+        let expected = Err(PadderError::FieldCount {
+            expected: 2,
+            found: 1,
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn write_record_length() {
+        let field = Field::new(
+            "date",
+            6,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+        );
+        let layout = Layout::new(vec![text("a", 2), field]);
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = layout.write(&[Value::from("ab"), Value::Null], &mut buffer);
+        let expected = Err(PadderError::Field {
+            name: "date".to_string(),
+            error: Box::new(PadderError::InvalidLength(8)),
+        });
+        assert_eq!(expected, output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn parse_strips_padding() {
+        let layout = Layout::new(vec![
+            text("name", 6),
+            numeric("count", 4),
+            Field::new(
+                "code",
+                5,
+                Alignment::Center,
+                Symbol::Hyphen,
+                FieldKind::Text,
+            ),
+        ]);
+        let output = layout.parse(b"ab c  0012-xy--").unwrap();
+        let expected: Vec<&[u8]> = vec![b"ab c", b"12", b"xy"];
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn parse_str_fields() {
+        let layout = Layout::new(vec![numeric("id", 3), text("name", 7)]);
+        let output = layout.parse_str("007hällo ").unwrap();
+        assert_eq!(vec!["7", "hällo"], output);
+    }
+
+    #[test]
+    fn parse_short_record() {
+        let layout = Layout::new(vec![text("a", 3), text("b", 3)]);
+        let output = layout.parse(b"abcde");
+        let expected = Err(PadderError::RecordLength {
+            expected: 6,
+            found: 5,
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn parse_long_record() {
+        let layout = Layout::new(vec![text("a", 3)]).with_lenient_trailing(true);
+        let output = layout.parse(b"abcd");
+        let expected = Err(PadderError::RecordLength {
+            expected: 3,
+            found: 4,
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn parse_lenient_trailing() {
+        let layout =
+            Layout::new(vec![text("a", 3), text("b", 4), text("c", 2)]).with_lenient_trailing(true);
+        let output = layout.parse(b"abcde").unwrap();
+        let expected: Vec<&[u8]> = vec![b"abc", b"de", b""];
+        assert_eq!(expected, output);
+
+        let output = layout.decode(b"abc").unwrap();
+        assert_eq!(
+            vec![Value::from("abc"), Value::from(""), Value::from("")],
+            output
+        );
+    }
+
+    #[test]
+    fn decode_numeric_fill() {
+        let zero = numeric("zero", 4).with_scale(2);
+        assert_eq!(Ok(Value::Decimal(0, 2)), zero.decode(b"0000"));
+        let blank = Field::new(
+            "blank",
+            4,
+            Alignment::Right,
+            Symbol::Whitespace,
+            FieldKind::Numeric,
+        );
+        assert_eq!(Ok(Value::Null), blank.decode(b"    "));
+        assert_eq!(Ok(Value::Integer(42)), blank.decode(b"  42"));
+    }
+
+    #[test]
+    fn decode_invalid_field() {
+        let layout = Layout::new(vec![text("a", 2), numeric("b", 3)]);
+        let output = layout.decode(b"ab1x3");
+        let expected = Err(PadderError::Field {
+            name: "b".to_string(),
+            error: Box::new(PadderError::InvalidDigit {
+                position: 1,
+                byte: b'x',
+            }),
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn write_decode_round_trip() {
+        let layout = Layout::new(vec![
+            text("name", 10),
+            numeric("amount", 9).with_scale(2),
+            Field::new(
+                "balance",
+                7,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Signed(Encoding::Ebcdic),
+            )
+            .with_scale(2),
+            Field::new(
+                "count",
+                3,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Packed { signed: true },
+            ),
+            Field::new(
+                "id",
+                8,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Binary {
+                    signed: true,
+                    endianness: Endianness::Big,
+                },
+            ),
+            Field::new(
+                "created",
+                14,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDDHHMMSS")),
+            ),
+            Field::new(
+                "active",
+                1,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::TrueFalse),
+            ),
+        ]);
+
+        let rows: Vec<Vec<Value>> = vec![
+            vec![
+                Value::from("Ågren"),
+                Value::Decimal(123456, 2),
+                Value::Decimal(-99, 2),
+                Value::Integer(-12345),
+                Value::Integer(i64::MIN),
+                Value::from(DateTime::new(2023, 12, 14, 23, 59, 59)),
+                Value::from(true),
+            ],
+            vec![
+                Value::from(""),
+                Value::Decimal(0, 2),
+                Value::Null,
+                Value::Null,
+                Value::Integer(0),
+                Value::Null,
+                Value::Null,
+            ],
+        ];
+
+        for values in rows {
+            let mut record: Vec<u8> = Vec::with_capacity(layout.width());
+            layout.write(&values, &mut record).unwrap();
+            assert_eq!(layout.width(), record.len());
+            assert_eq!(values, layout.decode(&record).unwrap());
+        }
+    }
+
+    #[test]
+    fn validate_layouts() {
+        assert!(matches!(
+            Layout::new(vec![]).validate(),
+            Err(PadderError::InvalidLayout(_))
+        ));
+        assert_eq!(Ok(()), Layout::new(vec![text("a", 3)]).validate());
+
+        let binary = Field::new(
+            "b",
+            3,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Binary {
+                signed: true,
+                endianness: Endianness::Big,
+            },
+        );
+        let output = Layout::new(vec![text("a", 3), binary]).validate();
+        assert_eq!(
+            Err(PadderError::Field {
+                name: "b".to_string(),
+                error: Box::new(PadderError::InvalidLength(3)),
+            }),
+            output
+        );
+
+        let date = Field::new(
+            "d",
+            6,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+        );
+        assert!(Layout::new(vec![date]).validate().is_err());
+        assert!(Layout::new(vec![text("z", 0)]).validate().is_err());
+
+        let amount = Field::new(
+            "amount",
+            20,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        );
+        assert_eq!(
+            Ok(()),
+            Layout::new(vec![amount.clone().with_scale(18)]).validate()
+        );
+        let output = Layout::new(vec![amount.clone().with_scale(19)]).validate();
+        assert!(matches!(output, Err(PadderError::Field { name, .. }) if name == "amount"));
+
+        for alignment in [Alignment::Left, Alignment::Center] {
+            let mut field = amount.clone();
+            field.alignment = alignment;
+            let output = Layout::new(vec![field.clone()]).validate();
+            assert!(matches!(output, Err(PadderError::Field { name, .. }) if name == "amount"));
+            field.kind = FieldKind::Signed(Encoding::Ascii);
+            assert!(Layout::new(vec![field.clone()]).validate().is_err());
+            field.symbol = Symbol::Whitespace;
+            assert_eq!(Ok(()), Layout::new(vec![field]).validate());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn layout_from_json() {
+        let spec = r#"{
+            "fields": [
+                { "name": "id", "width": 6, "symbol": "Zero", "kind": "Numeric" },
+                { "name": "name", "width": 8, "alignment": "Left" },
+                {
+                    "name": "amount",
+                    "width": 5,
+                    "kind": { "Packed": { "signed": true } },
+                    "scale": 2
+                },
+                { "name": "born", "width": 8, "kind": { "DateTime": "YYYYMMDD" } },
+                {
+                    "name": "note",
+                    "width": 4,
+                    "alignment": "Left",
+                    "overflow": "Truncate"
+                }
+            ],
+            "lenient_trailing": true
+        }"#;
+        let layout = Layout::from_json(spec).unwrap();
+
+        let expected = Layout::new(vec![
+            numeric("id", 6),
+            text("name", 8),
+            Field::new(
+                "amount",
+                5,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Packed { signed: true },
+            )
+            .with_scale(2),
+            Field::new(
+                "born",
+                8,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+            text("note", 4).with_overflow(Overflow::Truncate),
+        ])
+        .with_lenient_trailing(true);
+        assert_eq!(expected, layout);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn layout_json_round_trip() {
+        let layout = Layout::new(vec![
+            numeric("id", 4),
+            Field::new(
+                "flag",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+        ]);
+        let spec: String = layout.to_json().unwrap();
+        assert_eq!(Ok(layout), Layout::from_json(&spec));
+    }
+
+    #[cfg(all(feature = "toml", feature = "yaml"))]
+    #[test]
+    fn layout_toml_yaml_round_trip() {
+        let layout = Layout::new(vec![
+            numeric("id", 4),
+            Field::new(
+                "balance",
+                7,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Signed(Encoding::Ebcdic),
+            )
+            .with_scale(2),
+        ])
+        .with_lenient_trailing(true);
+        let spec: String = layout.to_toml().unwrap();
+        assert_eq!(Ok(layout.clone()), Layout::from_toml(&spec));
+        let spec: String = layout.to_yaml().unwrap();
+        assert_eq!(Ok(layout), Layout::from_yaml(&spec));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn layout_from_invalid_json() {
+        let output = Layout::from_json(r#"{ "fields": [ { "name": "a" } ] }"#);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+        let output = Layout::from_json(r#"{ "fields": [] }"#);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn layout_from_toml() {
+        let spec = r#"
+            [[fields]]
+            name = "id"
+            width = 6
+            symbol = "Zero"
+            kind = "Numeric"
+
+            [[fields]]
+            name = "balance"
+            width = 7
+            kind = { Signed = "Ascii" }
+            scale = 2
+        "#;
+        let layout = Layout::from_toml(spec).unwrap();
+        assert_eq!(13, layout.width());
+        assert_eq!(
+            Some(&FieldKind::Signed(Encoding::Ascii)),
+            layout.field("balance").map(|f| &f.kind)
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn layout_from_yaml() {
+        let spec = r#"
+fields:
+  - name: code
+    width: 2
+    alignment: Left
+  - name: active
+    width: 1
+    kind: !Boolean OneZero
+"#;
+        let layout = Layout::from_yaml(spec).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        layout
+            .write(&[Value::from("A"), Value::from(true)], &mut buffer)
+            .unwrap();
+        assert_eq!(b"A 1", buffer.as_slice());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn layout_from_path() {
+        let path = std::env::temp_dir().join("padder_layout_from_path.json");
+        fs::write(&path, r#"{ "fields": [ { "name": "a", "width": 3 } ] }"#).unwrap();
+        let layout = Layout::from_path(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(3, layout.width());
+
+        let output = Layout::from_path(std::env::temp_dir().join("padder_missing.json"));
+        assert!(matches!(
+            output,
+            Err(PadderError::Io {
+                kind: std::io::ErrorKind::NotFound,
+                ..
+            })
+        ));
+        let output = Layout::from_path("layout.xml");
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+    }
+}
@@ -22,7 +22,7 @@
 // SOFTWARE.
 //
 // File created: 2023-12-14
-// Last updated: 2024-05-10
+// Last updated: 2026-10-18
 //
 
 //!
@@ -90,6 +90,16 @@
 //! assert_eq!(expected, buffer);
 //! ```
 //!
+//! The padding can also be stripped again with the [`Unpad`] trait, which returns a
+//! sub-slice of the padded field without allocating any memory:
+//!
+//! ```
+//! use padder::*;
+//!
+//! let output: &str = "000123".unpad(Alignment::Right, Symbol::Zero);
+//! assert_eq!("123", output);
+//! ```
+//!
 //! For more inspiration and guidance on how to use this crate and its trait, please refer
 //! to the examples of the README in the project repository.
 //!
@@ -100,9 +110,70 @@ use serde::{Deserialize, Serialize};
 use std::clone;
 use std::fmt;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod binary;
+pub mod codes;
+#[cfg(feature = "csv")]
+pub mod convert;
+pub mod copybook;
+pub mod datetime;
+#[cfg(feature = "serde")]
+pub mod de;
+pub mod error;
+pub mod fit;
+pub mod infer;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod layout;
+pub mod packed;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod reader;
+pub mod record;
+#[cfg(feature = "serde")]
+pub mod ser;
+pub mod table;
+pub mod validate;
+pub mod value;
+pub mod writer;
+pub mod zoned;
+
+#[cfg(feature = "parquet")]
+pub use crate::parquet::{fixed_width_to_parquet, parquet_to_fixed_width};
+#[cfg(feature = "arrow")]
+pub use arrow::{BatchReader, BatchWriter};
+pub use binary::{decode_binary, encode_binary, Endianness};
+pub use codes::{BoolCodes, CodeTable};
+#[cfg(feature = "csv")]
+pub use convert::{csv_to_fixed_width, fixed_width_to_csv, Column, CsvOptions};
+pub use copybook::parse_copybook;
+pub use datetime::{DateTime, DateTimeFormat};
+#[cfg(feature = "serde")]
+pub use de::{from_record, RecordDeserializer};
+pub use error::PadderError;
+pub use fit::{AutoFit, Fit, Measure};
+pub use infer::infer_layout;
+#[cfg(feature = "serde")]
+pub use json::fixed_width_to_json_lines;
+pub use layout::{Field, FieldKind, Layout, Overflow};
+pub use packed::{decode_packed, encode_packed, packed_length};
+#[cfg(feature = "derive")]
+pub use padder_derive::FixedWidth;
+pub use reader::{FixedWidthReader, Row};
+pub use record::{FixedWidth, FixedWidthField};
+#[cfg(feature = "serde")]
+pub use ser::{to_record, RecordSerializer};
+pub use table::{Border, Table};
+pub use validate::{Issue, IssueKind, Report, Validator};
+pub use value::Value;
+pub use writer::{FixedWidthWriter, Terminator, TrailerField};
+pub use zoned::{decode_overpunch, decode_zoned, encode_overpunch, encode_zoned, Encoding};
+
 /// Exhaustive enum for the alternative ways to pad and format data.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum Alignment {
     Left,
     #[default]
@@ -110,7 +181,6 @@ pub enum Alignment {
     Center,
 }
 
-///
 impl Alignment {
     /// Get amount of chars to pad on either side of the [`Source`].
     pub fn left_right_padding(&self, diff: usize) -> (usize, usize) {
@@ -125,6 +195,7 @@ impl Alignment {
 /// Exhaustive enum for the supported padding symbols.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum Symbol {
     Hyphen,
     Underscore,
@@ -137,6 +208,7 @@ pub enum Symbol {
     Forwardslash,
     Asterisk,
     #[default]
+    #[cfg_attr(feature = "cli", value(alias = "space"))]
     Whitespace,
     Zero,
     One,
@@ -463,6 +535,77 @@ where
     }
 }
 
+/// A trait providing the inverse of [`Source::pad`], stripping the padding from a field.
+///
+/// The padding symbol is stripped from the side(s) of the field where [`Source::pad`]
+/// would have put it for the given [`Alignment`], that is, from the end for
+/// [`Alignment::Left`], from the start for [`Alignment::Right`], and from both sides for
+/// [`Alignment::Center`]. The result is a sub-slice of the field, so no memory is
+/// allocated.
+///
+/// Note that a field consisting only of the padding symbol is unpadded to an empty slice,
+/// which matters for numeric fields padded with [`Symbol::Zero`].
+pub trait Unpad {
+    type Output: ?Sized;
+
+    /// Strip the padding symbol from the field according to the alignment mode.
+    fn unpad(&self, mode: Alignment, symbol: Symbol) -> &Self::Output;
+}
+
+/// Trait implementation for a string slice.
+impl Unpad for str {
+    type Output = str;
+
+    fn unpad(&self, mode: Alignment, symbol: Symbol) -> &Self::Output {
+        let pad_char: char = symbol.into();
+        match mode {
+            Alignment::Left => self.trim_end_matches(pad_char),
+            Alignment::Right => self.trim_start_matches(pad_char),
+            Alignment::Center => self.trim_matches(pad_char),
+        }
+    }
+}
+
+/// Trait implementation for a slice of types `[T]`.
+impl<T> Unpad for [T]
+where
+    T: From<Symbol> + PartialEq,
+{
+    type Output = [T];
+
+    fn unpad(&self, mode: Alignment, symbol: Symbol) -> &Self::Output {
+        let pad_type: T = symbol.into();
+        let start: usize = match mode {
+            Alignment::Left => 0,
+            Alignment::Right | Alignment::Center => self
+                .iter()
+                .position(|t| *t != pad_type)
+                .unwrap_or(self.len()),
+        };
+        let end: usize = match mode {
+            Alignment::Right => self.len(),
+            Alignment::Left | Alignment::Center => self
+                .iter()
+                .rposition(|t| *t != pad_type)
+                .map_or(start, |idx| idx + 1),
+        };
+
+        &self[start..end]
+    }
+}
+
+/// Trait implementation for a `Vec<T>`, unpadding to a slice of the vector.
+impl<T> Unpad for Vec<T>
+where
+    T: From<Symbol> + PartialEq,
+{
+    type Output = [T];
+
+    fn unpad(&self, mode: Alignment, symbol: Symbol) -> &Self::Output {
+        self.as_slice().unpad(mode, symbol)
+    }
+}
+
 /// Wrapper for the [`Source`] trait implementation of its [`pad`] function.
 pub fn pad<S: Source>(source: S, width: usize, mode: Alignment, symbol: Symbol) -> S::Output {
     source.pad(width, mode, symbol)
@@ -479,6 +622,11 @@ pub fn pad_and_push_to_buffer<S: Source>(
     source.pad_and_push_to_buffer(width, mode, symbol, buffer);
 }
 
+/// Wrapper for the [`Unpad`] trait implementation of its [`unpad`] function.
+pub fn unpad<S: Unpad + ?Sized>(source: &S, mode: Alignment, symbol: Symbol) -> &S::Output {
+    source.unpad(mode, symbol)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1421,7 +1569,7 @@ mod tests {
         );
 
         let mut expected = String::from("aeirjgjkadmval,mr1873481 y uhaegr");
-        (0..(width - expected.len())).for_each(|_| expected.push_str("0"));
+        (0..(width - expected.len())).for_each(|_| expected.push('0'));
 
         assert_eq!(expected.as_bytes(), output);
         assert_ne!(expected.capacity(), output.capacity());
@@ -1432,7 +1580,7 @@ mod tests {
     fn wrapper_pad_vec_char_left_align_zero() {
         let output: Vec<char> = pad(vec!['a', 'b', 'c', '0'], 13, Alignment::Left, Symbol::Zero);
         let mut expected: Vec<char> = vec!['a', 'b', 'c', '0'];
-        expected.extend_from_slice(&vec!['0'; 9]);
+        expected.extend_from_slice(&['0'; 9]);
 
         assert_eq!(expected, output);
         assert_eq!(expected.capacity(), output.capacity());
@@ -1447,7 +1595,7 @@ mod tests {
             Symbol::Whitespace,
         );
         let mut expected: Vec<u8> = vec![b' '; 8];
-        expected.extend_from_slice(&vec![0u8, 2, 65, 8, 41]);
+        expected.extend_from_slice(&[0u8, 2, 65, 8, 41]);
 
         assert_eq!(expected, output);
         assert_ne!(expected.capacity(), output.capacity());
@@ -1508,7 +1656,7 @@ mod tests {
     fn pad_vec_u8_right_align_hyphen() {
         let output = vec![14u8, 12u8, 9u8].pad(5, Alignment::Right, Symbol::Hyphen);
         let mut expected = "--".as_bytes().to_vec();
-        expected.extend_from_slice(&vec![14u8, 12u8, 9u8]);
+        expected.extend_from_slice(&[14u8, 12u8, 9u8]);
 
         assert_eq!(expected, output);
         assert_ne!(expected.capacity(), output.capacity());
@@ -1518,7 +1666,7 @@ mod tests {
     fn pad_vec_u8_center_align_hyphen() {
         let output = vec![14u8, 12u8, 9u8].pad(5, Alignment::Center, Symbol::Hyphen);
         let mut expected = "-".as_bytes().to_vec();
-        expected.extend_from_slice(&vec![14u8, 12u8, 9u8]);
+        expected.extend_from_slice(&[14u8, 12u8, 9u8]);
         expected.extend_from_slice("-".as_bytes());
 
         assert_eq!(expected, output);
@@ -1733,6 +1881,71 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn unpad_str_left_align_whitespace() {
+        let output: &str = "hej   ".unpad(Alignment::Left, Symbol::Whitespace);
+        assert_eq!("hej", output);
+    }
+
+    #[test]
+    fn unpad_str_right_align_zero() {
+        let output: &str = "00009184".unpad(Alignment::Right, Symbol::Zero);
+        assert_eq!("9184", output);
+    }
+
+    #[test]
+    fn unpad_str_center_align_hyphen() {
+        let output: &str = "--hejjj---".unpad(Alignment::Center, Symbol::Hyphen);
+        assert_eq!("hejjj", output);
+    }
+
+    #[test]
+    fn unpad_str_keeps_inner_symbols() {
+        let output: &str = " a b  ".unpad(Alignment::Left, Symbol::Whitespace);
+        assert_eq!(" a b", output);
+    }
+
+    #[test]
+    fn unpad_str_only_padding() {
+        let output: &str = "0000".unpad(Alignment::Right, Symbol::Zero);
+        assert_eq!("", output);
+    }
+
+    #[test]
+    fn unpad_slice_byte_center_align_underscore() {
+        let source: &[u8] = &[b'_', 1, 2, b'_', b'_'];
+        let output: &[u8] = source.unpad(Alignment::Center, Symbol::Underscore);
+        assert_eq!(&[1u8, 2], output);
+    }
+
+    #[test]
+    fn unpad_slice_char_only_padding() {
+        let source: &[char] = &['*', '*', '*'];
+        let output: &[char] = source.unpad(Alignment::Center, Symbol::Asterisk);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn unpad_vec_u8_right_align_whitespace() {
+        let source: Vec<u8> = vec![b' ', b' ', 0u8, 2, 65];
+        let output: &[u8] = source.unpad(Alignment::Right, Symbol::Whitespace);
+        assert_eq!(&[0u8, 2, 65], output);
+    }
+
+    #[test]
+    fn wrapper_unpad_round_trip() {
+        let width: usize = 12;
+        for mode in [Alignment::Left, Alignment::Right, Alignment::Center] {
+            let padded: String = pad("abc", width, mode, Symbol::Dot);
+            let output: &str = unpad(padded.as_str(), mode, Symbol::Dot);
+            assert_eq!("abc", output);
+
+            let padded: Vec<u8> = pad("abc".as_bytes(), width, mode, Symbol::Dot);
+            let output: &[u8] = unpad(&padded, mode, Symbol::Dot);
+            assert_eq!("abc".as_bytes(), output);
+        }
+    }
+
     #[test]
     fn default_alignment() {
         let expected = Alignment::Right;
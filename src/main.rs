@@ -0,0 +1,340 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! The `padder` command-line tool, which exposes the library to shell scripts.
+//!
+//! ```text
+//! padder pad --width 10 --align right --symbol zero 42
+//! padder convert csv2fw --layout spec.json input.csv -o output.txt
+//! padder convert fw2csv --layout spec.json --format jsonl input.txt
+//! padder infer --lines 100 legacy.txt > spec.json
+//! padder validate --layout spec.json --json input.txt
+//! ```
+//!
+//! Input is read from standard input and output is written to standard output unless
+//! files are given. Errors are printed to standard error with a non-zero exit code.
+//!
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use padder::{
+    csv_to_fixed_width, fixed_width_to_csv, fixed_width_to_json_lines, infer_layout, Alignment,
+    CsvOptions, FixedWidthReader, FixedWidthWriter, Layout, PadderError, Report, Symbol,
+    Terminator, Validator,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "padder",
+    version,
+    about = "Pad text and convert fixed-width files."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Pad each text, or each line of the input if no text is given, to a fixed width.
+    Pad {
+        /// The width to pad to in characters, where longer text is truncated.
+        #[arg(long)]
+        width: usize,
+        #[arg(long, value_enum, default_value_t = Alignment::Right)]
+        align: Alignment,
+        #[arg(long, value_enum, default_value_t = Symbol::Whitespace)]
+        symbol: Symbol,
+        text: Vec<String>,
+    },
+    /// Convert between delimited and fixed-width files.
+    Convert {
+        #[command(subcommand)]
+        conversion: Conversion,
+    },
+    /// Infer the layout of an undocumented fixed-width file from its first lines and
+    /// print it as a JSON spec.
+    Infer {
+        /// The number of lines to sample.
+        #[arg(long, default_value_t = 1000)]
+        lines: usize,
+        /// The fixed-width file, or standard input if not given.
+        input: Option<PathBuf>,
+    },
+    /// Check every record of a fixed-width file against a layout, reporting the line,
+    /// column and field of each issue.
+    Validate {
+        #[command(flatten)]
+        records: Records,
+        /// Print the report as JSON instead of one issue per line.
+        #[arg(long)]
+        json: bool,
+        /// The fixed-width file, or standard input if not given.
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum Conversion {
+    /// Convert a CSV file to a fixed-width file, taking each field from the column with
+    /// the same header name, or position if there are no headers.
+    Csv2fw {
+        #[command(flatten)]
+        records: Records,
+        #[command(flatten)]
+        delimited: Delimited,
+        /// The CSV file, or standard input if not given.
+        input: Option<PathBuf>,
+        /// The fixed-width file, or standard output if not given.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Convert a fixed-width file to a CSV, TSV or JSON Lines file.
+    Fw2csv {
+        #[command(flatten)]
+        records: Records,
+        #[command(flatten)]
+        delimited: Delimited,
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// The fixed-width file, or standard input if not given.
+        input: Option<PathBuf>,
+        /// The converted file, or standard output if not given.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// The options of the fixed-width side of a command.
+#[derive(clap::Args)]
+struct Records {
+    /// The layout spec of the records, as a JSON, TOML or YAML file.
+    #[arg(long)]
+    layout: PathBuf,
+    #[arg(long, value_enum, default_value_t = Terminator::Lf)]
+    terminator: Terminator,
+}
+
+/// The options of the delimited side of a command.
+#[derive(clap::Args)]
+struct Delimited {
+    #[arg(long, default_value_t = ',')]
+    delimiter: char,
+    #[arg(long, default_value_t = '"')]
+    quote: char,
+    /// The delimited file has no header row.
+    #[arg(long)]
+    no_headers: bool,
+}
+
+/// Exhaustive enum for the formats that fixed-width files are converted to.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Tsv,
+    Jsonl,
+}
+
+impl Delimited {
+    /// Get the CSV options, failing if the delimiter or quote is not a single byte.
+    fn options(&self) -> Result<CsvOptions, PadderError> {
+        let byte = |c: char| {
+            u8::try_from(c).ok().filter(u8::is_ascii).ok_or_else(|| {
+                PadderError::InvalidValue(format!("'{}' is not an ASCII character", c))
+            })
+        };
+        Ok(CsvOptions::default()
+            .with_delimiter(byte(self.delimiter)?)
+            .with_quote(byte(self.quote)?)
+            .with_headers(!self.no_headers))
+    }
+}
+
+/// Open the file at the path for reading, or standard input if there is none.
+fn open(path: Option<&Path>) -> Result<Box<dyn BufRead>, PadderError> {
+    Ok(match path {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    })
+}
+
+/// Create the file at the path for writing, or standard output if there is none.
+fn create(path: Option<&Path>) -> Result<Box<dyn Write>, PadderError> {
+    Ok(match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    })
+}
+
+/// Pad or truncate the text to the width in characters, so that text outside of ASCII
+/// is never split in the middle of a character.
+fn pad_chars(text: &str, width: usize, align: Alignment, symbol: Symbol) -> String {
+    let count: usize = text.chars().count();
+    if count > width {
+        let skip: usize = match align {
+            Alignment::Left => 0,
+            Alignment::Right => count - width,
+            Alignment::Center => count / 2 - width / 2,
+        };
+        return text.chars().skip(skip).take(width).collect();
+    }
+
+    let (left, right) = align.left_right_padding(width - count);
+    let fill: char = symbol.into();
+    let mut output = String::with_capacity(text.len() + left + right);
+    (0..left).for_each(|_| output.push(fill));
+    output.push_str(text);
+    (0..right).for_each(|_| output.push(fill));
+    output
+}
+
+/// Pad each text, or each line of the input, and write it as a line to the output.
+fn run_pad(
+    width: usize,
+    align: Alignment,
+    symbol: Symbol,
+    text: &[String],
+) -> Result<(), PadderError> {
+    let mut output: Box<dyn Write> = create(None)?;
+    let mut write = |text: &str| -> Result<(), PadderError> {
+        output.write_all(pad_chars(text, width, align, symbol).as_bytes())?;
+        output.write_all(b"\n")?;
+        Ok(())
+    };
+
+    if text.is_empty() {
+        for line in io::stdin().lock().lines() {
+            write(line?.trim_end_matches('\r'))?;
+        }
+    } else {
+        text.iter().try_for_each(|t| write(t))?;
+    }
+    output.flush()?;
+    Ok(())
+}
+
+/// Run the command, returning whether it succeeded.
+fn run(command: Command) -> Result<bool, PadderError> {
+    match command {
+        Command::Pad {
+            width,
+            align,
+            symbol,
+            text,
+        } => run_pad(width, align, symbol, &text)?,
+        Command::Convert {
+            conversion:
+                Conversion::Csv2fw {
+                    records,
+                    delimited,
+                    input,
+                    output,
+                },
+        } => {
+            let layout: Layout = Layout::from_path(&records.layout)?;
+            let mut writer = FixedWidthWriter::new(create(output.as_deref())?, layout)
+                .with_terminator(records.terminator);
+            csv_to_fixed_width(open(input.as_deref())?, &mut writer, &delimited.options()?)?;
+            writer.finish()?;
+        }
+        Command::Convert {
+            conversion:
+                Conversion::Fw2csv {
+                    records,
+                    delimited,
+                    format,
+                    input,
+                    output,
+                },
+        } => {
+            let layout: Layout = Layout::from_path(&records.layout)?;
+            let reader = FixedWidthReader::new(open(input.as_deref())?, layout)
+                .with_terminator(records.terminator);
+            let mut output: Box<dyn Write> = create(output.as_deref())?;
+            match format {
+                Format::Csv => fixed_width_to_csv(reader, &mut output, &delimited.options()?)?,
+                Format::Tsv => {
+                    let options = delimited.options()?.with_delimiter(b'\t');
+                    fixed_width_to_csv(reader, &mut output, &options)?
+                }
+                Format::Jsonl => fixed_width_to_json_lines(reader, &mut output)?,
+            };
+        }
+        Command::Infer { lines, input } => {
+            let sample: Vec<String> = open(input.as_deref())?
+                .lines()
+                .take(lines)
+                .collect::<Result<_, _>>()?;
+            println!("{}", infer_layout(&sample)?.to_json()?);
+        }
+        Command::Validate {
+            records,
+            json,
+            input,
+        } => {
+            let layout: Layout = Layout::from_path(&records.layout)?;
+            let mut data: Vec<u8> = Vec::new();
+            open(input.as_deref())?.read_to_end(&mut data)?;
+            let report: Report = Validator::new(layout)
+                .with_terminator(records.terminator)
+                .validate(&data);
+
+            if json {
+                println!("{}", report.to_json()?);
+            } else {
+                report
+                    .issues
+                    .iter()
+                    .for_each(|issue| eprintln!("{}", issue));
+                println!(
+                    "{} valid, {} invalid records",
+                    report.records - report.invalid,
+                    report.invalid
+                );
+            }
+            return Ok(report.is_valid());
+        }
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("padder: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Packed decimal (COMP-3) encoding and decoding.
+//!
+//! A packed decimal field stores two digits per byte, one in each nibble, followed by a
+//! trailing sign nibble. The digits are right-aligned and padded with zero nibbles to
+//! fill the byte length of the field, which holds at most `2 * length - 1` digits.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(4);
+//! encode_packed(-12345, 4, true, &mut buffer).unwrap();
+//! assert_eq!(vec![0x00, 0x12, 0x34, 0x5D], buffer);
+//!
+//! let value: i64 = decode_packed(&buffer).unwrap();
+//! assert_eq!(-12345, value);
+//! ```
+//!
+
+use crate::zoned::{accumulate, digits};
+use crate::{pad, Alignment, PadderError, Symbol};
+
+const SIGN_POSITIVE: u8 = 0x0C;
+const SIGN_NEGATIVE: u8 = 0x0D;
+const SIGN_UNSIGNED: u8 = 0x0F;
+
+/// Get the number of bytes required to hold the given number of digits packed.
+pub fn packed_length(digits: usize) -> usize {
+    digits / 2 + 1
+}
+
+/// Encode a value as a packed decimal field of the given byte length and push it to
+/// the buffer.
+///
+/// Signed fields get the sign nibble `0xC` for positive and `0xD` for negative values,
+/// while unsigned fields always get the sign nibble `0xF` and reject negative values.
+pub fn encode_packed(
+    value: i64,
+    length: usize,
+    signed: bool,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    if !signed && value < 0 {
+        return Err(PadderError::OutOfRange);
+    }
+
+    let capacity: usize = (2 * length).saturating_sub(1);
+    let mut scratch = [0u8; 20];
+    let digits: &[u8] = digits(value.unsigned_abs(), &mut scratch);
+    if digits.len() > capacity {
+        return Err(PadderError::Overflow {
+            width: capacity,
+            required: digits.len(),
+        });
+    }
+
+    let sign: u8 = match (signed, value < 0) {
+        (false, _) => SIGN_UNSIGNED,
+        (true, false) => SIGN_POSITIVE,
+        (true, true) => SIGN_NEGATIVE,
+    };
+
+    let mut nibbles: Vec<u8> = pad(digits, capacity, Alignment::Right, Symbol::Zero);
+    nibbles.iter_mut().for_each(|n| *n -= b'0');
+    nibbles.push(sign);
+
+    buffer.extend(nibbles.chunks_exact(2).map(|pair| (pair[0] << 4) | pair[1]));
+
+    Ok(())
+}
+
+/// Decode a packed decimal field.
+///
+/// The sign nibbles `0xA`, `0xC`, `0xE` and `0xF` are decoded as positive, and the sign
+/// nibbles `0xB` and `0xD` as negative.
+pub fn decode_packed(field: &[u8]) -> Result<i64, PadderError> {
+    let (&last, leading) = field.split_last().ok_or(PadderError::EmptyField)?;
+
+    let negative: bool = match last & 0x0F {
+        0x0A | 0x0C | 0x0E | 0x0F => false,
+        0x0B | 0x0D => true,
+        _ => return Err(PadderError::InvalidSign(last)),
+    };
+
+    let mut magnitude: u64 = 0;
+    for (position, &byte) in leading.iter().enumerate() {
+        let (high, low) = (byte >> 4, byte & 0x0F);
+        if high > 9 || low > 9 {
+            return Err(PadderError::InvalidDigit { position, byte });
+        }
+        magnitude = accumulate(accumulate(magnitude, high)?, low)?;
+    }
+
+    let high: u8 = last >> 4;
+    if high > 9 {
+        return Err(PadderError::InvalidDigit {
+            position: leading.len(),
+            byte: last,
+        });
+    }
+    magnitude = accumulate(magnitude, high)?;
+
+    let value: i128 = if negative {
+        -(magnitude as i128)
+    } else {
+        magnitude as i128
+    };
+
+    i64::try_from(value).map_err(|_| PadderError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_length_from_digits() {
+        assert_eq!(1, packed_length(1));
+        assert_eq!(3, packed_length(4));
+        assert_eq!(3, packed_length(5));
+        assert_eq!(10, packed_length(18));
+    }
+
+    #[test]
+    fn encode_packed_positive() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_packed(12345, 3, true, &mut buffer).unwrap();
+        assert_eq!(vec![0x12, 0x34, 0x5C], buffer);
+    }
+
+    #[test]
+    fn encode_packed_negative_padded() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_packed(-7, 3, true, &mut buffer).unwrap();
+        assert_eq!(vec![0x00, 0x00, 0x7D], buffer);
+    }
+
+    #[test]
+    fn encode_packed_unsigned() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_packed(0, 2, false, &mut buffer).unwrap();
+        assert_eq!(vec![0x00, 0x0F], buffer);
+    }
+
+    #[test]
+    fn encode_packed_unsigned_negative() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = encode_packed(-1, 2, false, &mut buffer);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+    }
+
+    #[test]
+    fn encode_packed_overflow() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = encode_packed(1234, 2, true, &mut buffer);
+        let expected = Err(PadderError::Overflow {
+            width: 3,
+            required: 4,
+        });
+        assert_eq!(expected, output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn decode_packed_alternative_signs() {
+        assert_eq!(Ok(12), decode_packed(&[0x01, 0x2F]));
+        assert_eq!(Ok(-12), decode_packed(&[0x01, 0x2B]));
+    }
+
+    #[test]
+    fn decode_packed_invalid_sign() {
+        let output = decode_packed(&[0x01, 0x23]);
+        assert_eq!(Err(PadderError::InvalidSign(0x23)), output);
+    }
+
+    #[test]
+    fn decode_packed_invalid_digit() {
+        let output = decode_packed(&[0x1A, 0x2C]);
+        let expected = Err(PadderError::InvalidDigit {
+            position: 0,
+            byte: 0x1A,
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn packed_round_trip() {
+        let values: [i64; 7] = [0, 1, -1, 99, -100, 123456789012345678, i64::MIN];
+        for value in values {
+            let mut buffer: Vec<u8> = Vec::new();
+            encode_packed(value, 10, true, &mut buffer).unwrap();
+            assert_eq!(10, buffer.len());
+            assert_eq!(Ok(value), decode_packed(&buffer));
+        }
+    }
+}
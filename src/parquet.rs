@@ -0,0 +1,238 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Conversion between Parquet files and fixed-width files.
+//!
+//! Both directions go through Arrow record batches, so a single [`Layout`], for example
+//! loaded from a spec with [`Layout::from_path`], describes the widths and padding on
+//! both sides. [`fixed_width_to_parquet`] parses the records with a [`BatchReader`] and
+//! writes the batches as a Snappy compressed Parquet file, and [`parquet_to_fixed_width`]
+//! reads the batches of a Parquet file and writes them with a [`BatchWriter`].
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("name", 4, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//! let name = format!("padder_parquet_example_{}.parquet", std::process::id());
+//! let path = std::env::temp_dir().join(name);
+//!
+//! let reader = BatchReader::new(layout.clone()).unwrap();
+//! let file = std::fs::File::create(&path).unwrap();
+//! let count: u64 = fixed_width_to_parquet(b"acme001250\nb   000300\n", &reader, file).unwrap();
+//! assert_eq!(2, count);
+//!
+//! let file = std::fs::File::open(&path).unwrap();
+//! let output: Vec<u8> = parquet_to_fixed_width(file, layout, Vec::new()).unwrap();
+//! assert_eq!(b"acme001250\nb   000300\n", output.as_slice());
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+//!
+
+use std::io;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::ChunkReader;
+
+use crate::arrow::{BatchReader, BatchWriter};
+use crate::layout::Layout;
+use crate::PadderError;
+
+/// Convert a Parquet error into a [`PadderError`].
+fn parquet_error(error: ParquetError) -> PadderError {
+    PadderError::InvalidValue(format!("parquet: {}", error))
+}
+
+/// Convert an Arrow error into a [`PadderError`].
+fn arrow_error(error: ArrowError) -> PadderError {
+    match error {
+        ArrowError::IoError(message, error) => PadderError::Io {
+            kind: error.kind(),
+            message,
+        },
+        error => PadderError::InvalidValue(format!("arrow: {}", error)),
+    }
+}
+
+/// Parse the fixed-width records of the input with the reader and write them as a
+/// Parquet file to the output, returning the number of records converted.
+///
+/// The input is usually a memory-mapped file, and the records are parsed in parallel
+/// and written batch by batch as they are parsed, see [`BatchReader::for_each_batch`].
+pub fn fixed_width_to_parquet<W: io::Write + Send>(
+    input: &[u8],
+    reader: &BatchReader,
+    output: W,
+) -> Result<u64, PadderError> {
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer: ArrowWriter<W> =
+        ArrowWriter::try_new(output, reader.schema(), Some(properties)).map_err(parquet_error)?;
+
+    let count: u64 = reader.for_each_batch(input, |batch: RecordBatch| {
+        writer.write(&batch).map_err(parquet_error)
+    })?;
+    writer.close().map_err(parquet_error)?;
+    Ok(count)
+}
+
+/// Read the record batches of the Parquet input and write them as fixed-width records
+/// with the layout to the output, which is flushed and returned.
+///
+/// Every field of the layout takes the column with the same name, see
+/// [`BatchWriter::new`].
+pub fn parquet_to_fixed_width<R: ChunkReader + 'static, W: io::Write>(
+    input: R,
+    layout: Layout,
+    output: W,
+) -> Result<W, PadderError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(input).map_err(parquet_error)?;
+    let schema: SchemaRef = builder.schema().clone();
+    let reader: ParquetRecordBatchReader = builder.build().map_err(parquet_error)?;
+
+    let mut writer: BatchWriter<W> = BatchWriter::new(output, schema, layout)?;
+    for batch in reader {
+        writer.write(&batch.map_err(arrow_error)?)?;
+    }
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::{Alignment, BoolCodes, DateTimeFormat, Symbol};
+
+    use std::fs::File;
+    use std::path::PathBuf;
+
+    /// Get a path in the temporary directory that is unique to the test and process.
+    fn temp_path(name: &str) -> PathBuf {
+        let name: String = format!("padder_parquet_{}_{}.parquet", name, std::process::id());
+        std::env::temp_dir().join(name)
+    }
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new(
+                "name",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "amount",
+                7,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(3),
+            Field::new(
+                "flag",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+            Field::new(
+                "day",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+            Field::new(
+                "time",
+                6,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("HHMMSS")),
+            ),
+        ])
+    }
+
+    #[test]
+    fn parquet_round_trip() {
+        let input: &[u8] = concat!(
+            "acme0012500Y20240510083001\r\n",
+            "b   0000003N        235959\n\n",
+            "    9999999         000000\n",
+        )
+        .as_bytes();
+        let path = temp_path("round_trip");
+
+        let reader = BatchReader::new(layout()).unwrap().with_batch_size(2);
+        let count = fixed_width_to_parquet(input, &reader, File::create(&path).unwrap());
+        assert_eq!(Ok(3), count);
+
+        let output = parquet_to_fixed_width(File::open(&path).unwrap(), layout(), Vec::new());
+        std::fs::remove_file(&path).unwrap();
+        let expected: &str = concat!(
+            "acme0012500Y20240510083001\n",
+            "b   0000003N        235959\n",
+            "    9999999         000000\n",
+        );
+        assert_eq!(expected.as_bytes(), output.unwrap().as_slice());
+    }
+
+    #[test]
+    fn parquet_errors() {
+        let reader = BatchReader::new(layout()).unwrap();
+        let output = fixed_width_to_parquet(b"acme\n", &reader, Vec::new());
+        assert!(matches!(output, Err(PadderError::Record { line: 1, .. })));
+
+        let path = temp_path("errors");
+        std::fs::write(&path, b"not parquet").unwrap();
+        let output = parquet_to_fixed_width(File::open(&path).unwrap(), layout(), Vec::new());
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+
+        let count = fixed_width_to_parquet(b"", &reader, File::create(&path).unwrap());
+        assert_eq!(Ok(0), count);
+        let other = Layout::new(vec![Field::new(
+            "missing",
+            1,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )]);
+        let output = parquet_to_fixed_width(File::open(&path).unwrap(), other, Vec::new());
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+    }
+}
@@ -0,0 +1,450 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Streaming fixed-width file reader.
+//!
+//! A [`FixedWidthReader`] iterates the records of any [`io::BufRead`] and decodes each
+//! of them into a [`Row`] of values. Files with several record types, like a header,
+//! detail records and a trailer, are read by selecting the layout of each record from a
+//! record type code at a fixed position of the record, the discriminator. Errors carry
+//! the line number and byte offset of the record they occurred in.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let header = Layout::new(vec![
+//!     Field::new("type", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("date", 8, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//! ]);
+//! let detail = Layout::new(vec![
+//!     Field::new("type", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//!
+//! let input = "0120240131\n02001250\n02000300\n";
+//! let reader = FixedWidthReader::with_record_types(input.as_bytes(), 0, 2)
+//!     .with_layout("01", header)
+//!     .with_layout("02", detail);
+//!
+//! let rows: Vec<Row> = reader.collect::<Result<_, _>>().unwrap();
+//! assert_eq!(3, rows.len());
+//! assert_eq!(Some("01"), rows[0].code());
+//! assert_eq!(Some(&Value::Decimal(1250, 2)), rows[1].get("amount"));
+//! assert_eq!(3, rows[2].line);
+//! ```
+//!
+
+use std::io;
+use std::sync::Arc;
+
+use crate::layout::Layout;
+use crate::value::Value;
+use crate::writer::Terminator;
+use crate::PadderError;
+
+/// The offset, record type code and layout of the record read into the buffer.
+type Located = (u64, Option<String>, Arc<Layout>);
+
+/// A decoded record together with its position in the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    /// The line number of the record, starting from one, or the record number for
+    /// block formats without terminators.
+    pub line: u64,
+    /// The byte offset of the start of the record in the input.
+    pub offset: u64,
+    code: Option<String>,
+    layout: Arc<Layout>,
+    values: Vec<Value<'static>>,
+}
+
+impl Row {
+    /// Get the record type code of the row, if the reader has a discriminator.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Get the layout the row was decoded with.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Get the values of the row, one per field of its layout.
+    pub fn values(&self) -> &[Value<'static>] {
+        &self.values
+    }
+
+    /// Get the value of the field with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&Value<'static>> {
+        self.layout.position(name).map(|i| &self.values[i])
+    }
+
+    /// Convert the row into its values.
+    pub fn into_values(self) -> Vec<Value<'static>> {
+        self.values
+    }
+}
+
+/// A streaming reader of fixed-width records, with one or more record layouts.
+pub struct FixedWidthReader<R: io::BufRead> {
+    reader: R,
+    terminator: Terminator,
+    /// The offset and width of the record type code in each record.
+    discriminator: Option<(usize, usize)>,
+    layouts: Vec<(String, Arc<Layout>)>,
+    default: Option<Arc<Layout>>,
+    line: u64,
+    offset: u64,
+    buffer: Vec<u8>,
+}
+
+impl<R: io::BufRead> FixedWidthReader<R> {
+    /// Create a new [`FixedWidthReader`] that reads every record with the same layout,
+    /// where records are terminated by line feeds.
+    pub fn new(reader: R, layout: Layout) -> Self {
+        Self {
+            reader,
+            terminator: Terminator::default(),
+            discriminator: None,
+            layouts: Vec::new(),
+            default: Some(Arc::new(layout)),
+            line: 0,
+            offset: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Create a new [`FixedWidthReader`] that selects the layout of each record by the
+    /// record type code found at the given offset and width, see
+    /// [`FixedWidthReader::with_layout`].
+    pub fn with_record_types(reader: R, offset: usize, width: usize) -> Self {
+        Self {
+            reader,
+            terminator: Terminator::default(),
+            discriminator: Some((offset, width)),
+            layouts: Vec::new(),
+            default: None,
+            line: 0,
+            offset: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Set the layout of the records with the given record type code.
+    pub fn with_layout(mut self, code: &str, layout: Layout) -> Self {
+        self.layouts.push((code.to_string(), Arc::new(layout)));
+        self
+    }
+
+    /// Set the terminator of the records.
+    ///
+    /// Both `\n` and `\r\n` are accepted as line endings with [`Terminator::Lf`] and
+    /// [`Terminator::CrLf`], while [`Terminator::None`] reads each record by the width
+    /// of its layout.
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Wrap the error with the position of the current record.
+    fn error(&self, offset: u64, error: PadderError) -> PadderError {
+        PadderError::Record {
+            line: self.line,
+            offset,
+            error: Box::new(error),
+        }
+    }
+
+    /// Get the record type code and layout of the record starting with the given bytes.
+    fn select(&self, record: &[u8]) -> Result<(Option<String>, Arc<Layout>), PadderError> {
+        let Some((offset, width)) = self.discriminator else {
+            let layout = self.default.clone().expect("reader without record types");
+            return Ok((None, layout));
+        };
+
+        let code: &[u8] = record
+            .get(offset..offset + width)
+            .ok_or(PadderError::RecordLength {
+                expected: offset + width,
+                found: record.len(),
+            })?;
+        let code: String = String::from_utf8_lossy(code).into_owned();
+        match self.layouts.iter().find(|(c, _)| *c == code) {
+            Some((_, layout)) => Ok((Some(code), layout.clone())),
+            None => Err(PadderError::UnknownCode(code)),
+        }
+    }
+
+    /// Read the next line into the buffer without its line ending, skipping empty lines.
+    /// Returns the offset of the line, or [`None`] at the end of the input.
+    fn read_line(&mut self) -> Result<Option<u64>, PadderError> {
+        loop {
+            self.buffer.clear();
+            let read: usize = self.reader.read_until(b'\n', &mut self.buffer)?;
+            if read == 0 {
+                return Ok(None);
+            }
+
+            let offset: u64 = self.offset;
+            self.offset += read as u64;
+            self.line += 1;
+
+            if self.buffer.last() == Some(&b'\n') {
+                self.buffer.pop();
+                if self.buffer.last() == Some(&b'\r') {
+                    self.buffer.pop();
+                }
+            }
+            if !self.buffer.is_empty() {
+                return Ok(Some(offset));
+            }
+        }
+    }
+
+    /// Fill the buffer with exactly the given number of bytes, failing if the input ends
+    /// in the middle of the record.
+    fn read_exact(&mut self, length: usize) -> Result<usize, PadderError> {
+        let start: usize = self.buffer.len();
+        self.buffer.resize(length, 0);
+        let mut filled: usize = start;
+        while filled < length {
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        self.buffer.truncate(filled);
+        self.offset += (filled - start) as u64;
+        Ok(filled)
+    }
+
+    /// Read the next record of a block format into the buffer, returning its offset, or
+    /// [`None`] at the end of the input.
+    fn read_block(&mut self) -> Result<Option<Located>, PadderError> {
+        self.buffer.clear();
+        let offset: u64 = self.offset;
+        let prefix: usize = match self.discriminator {
+            Some((start, width)) => start + width,
+            None => self.default.as_ref().map_or(0, |l| l.width()),
+        };
+
+        if self.read_exact(prefix)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+
+        let (code, layout) = self
+            .select(&self.buffer)
+            .map_err(|error| self.error(offset, error))?;
+        let width: usize = layout.width();
+        if width > self.buffer.len() {
+            self.read_exact(width)?;
+        }
+        if self.buffer.len() != width {
+            let error = PadderError::RecordLength {
+                expected: width,
+                found: self.buffer.len(),
+            };
+            return Err(self.error(offset, error));
+        }
+        Ok(Some((offset, code, layout)))
+    }
+
+    /// Read and decode the next record, or [`None`] at the end of the input.
+    pub fn read_row(&mut self) -> Result<Option<Row>, PadderError> {
+        let (offset, code, layout) = match self.terminator {
+            Terminator::None => match self.read_block()? {
+                Some(record) => record,
+                None => return Ok(None),
+            },
+            Terminator::Lf | Terminator::CrLf => {
+                let Some(offset) = self.read_line()? else {
+                    return Ok(None);
+                };
+                let (code, layout) = self
+                    .select(&self.buffer)
+                    .map_err(|error| self.error(offset, error))?;
+                (offset, code, layout)
+            }
+        };
+
+        let values: Vec<Value<'static>> = match layout.decode(&self.buffer) {
+            Ok(values) => values.into_iter().map(Value::into_owned).collect(),
+            Err(error) => return Err(self.error(offset, error)),
+        };
+
+        Ok(Some(Row {
+            line: self.line,
+            offset,
+            code,
+            layout,
+            values,
+        }))
+    }
+}
+
+impl<R: io::BufRead> Iterator for FixedWidthReader<R> {
+    type Item = Result<Row, PadderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_row().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::{Alignment, Symbol};
+
+    fn text(name: &str, width: usize) -> Field {
+        Field::new(
+            name,
+            width,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )
+    }
+
+    fn numeric(name: &str, width: usize) -> Field {
+        Field::new(
+            name,
+            width,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )
+    }
+
+    fn reader(input: &[u8]) -> FixedWidthReader<&[u8]> {
+        FixedWidthReader::with_record_types(input, 0, 2)
+            .with_layout("01", Layout::new(vec![text("type", 2), text("name", 4)]))
+            .with_layout(
+                "02",
+                Layout::new(vec![text("type", 2), numeric("amount", 3)]),
+            )
+            .with_layout(
+                "99",
+                Layout::new(vec![text("type", 2), numeric("count", 2)]),
+            )
+    }
+
+    #[test]
+    fn read_single_layout() {
+        let layout = Layout::new(vec![text("name", 3), numeric("count", 2)]);
+        let input = "abc01\r\nde 10\r\n\r\n";
+        let rows: Vec<Row> = FixedWidthReader::new(input.as_bytes(), layout)
+            .with_terminator(Terminator::CrLf)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(2, rows.len());
+        assert_eq!(None, rows[0].code());
+        assert_eq!(Some(&Value::from("de")), rows[1].get("name"));
+        assert_eq!((2, 7), (rows[1].line, rows[1].offset));
+    }
+
+    #[test]
+    fn read_record_types() {
+        let rows: Vec<Row> = reader(b"01acme\n02042\n02007\n9902")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let codes: Vec<Option<&str>> = rows.iter().map(Row::code).collect();
+        assert_eq!(vec![Some("01"), Some("02"), Some("02"), Some("99")], codes);
+        assert_eq!(Some(&Value::Integer(42)), rows[1].get("amount"));
+        assert_eq!(Some(&Value::Integer(2)), rows[3].get("count"));
+        assert_eq!(None, rows[3].get("amount"));
+        assert_eq!(
+            vec![0, 7, 13, 19],
+            rows.iter().map(|r| r.offset).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_block_format() {
+        let rows: Vec<Row> = reader(b"01acme020429902")
+            .with_terminator(Terminator::None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(3, rows.len());
+        assert_eq!((3, 11), (rows[2].line, rows[2].offset));
+        assert_eq!(Some(&Value::Integer(2)), rows[2].get("count"));
+
+        let layout = Layout::new(vec![text("a", 2), numeric("b", 2)]);
+        let rows: Vec<Row> = FixedWidthReader::new(&b"ab01cd02"[..], layout)
+            .with_terminator(Terminator::None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(Some(&Value::Integer(2)), rows[1].get("b"));
+    }
+
+    #[test]
+    fn read_errors_with_position() {
+        let mut reader = reader(b"01acme\n03xyz\n02x42\n020\n");
+        assert!(reader.next().unwrap().is_ok());
+
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            PadderError::Record {
+                line: 2,
+                offset: 7,
+                error: Box::new(PadderError::UnknownCode("03".to_string())),
+            },
+            error
+        );
+
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(matches!(
+            error,
+            PadderError::Record { line: 3, offset: 13, ref error } if matches!(**error, PadderError::Field { .. })
+        ));
+
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(matches!(
+            error,
+            PadderError::Record { line: 4, offset: 19, ref error } if matches!(**error, PadderError::RecordLength { .. })
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn read_truncated_block() {
+        let mut reader = reader(b"0204").with_terminator(Terminator::None);
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(matches!(
+            error,
+            PadderError::Record {
+                line: 1,
+                offset: 0,
+                ..
+            }
+        ));
+    }
+}
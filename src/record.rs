@@ -0,0 +1,508 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Reading and writing structs as fixed-width records.
+//!
+//! A struct implementing [`FixedWidth`] knows how to pad each of its fields into a record
+//! and how to read them back. The implementation is normally generated with
+//! `#[derive(FixedWidth)]` from the `derive` feature, where every field is annotated with
+//! its width, alignment and symbol, and has a type implementing [`FixedWidthField`].
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use padder::*;
+//!
+//! #[derive(FixedWidth, Debug, PartialEq)]
+//! struct Payment {
+//!     #[fixed_width(width = 8, align = "left")]
+//!     payee: String,
+//!     #[fixed_width(width = 6, align = "right", symbol = "zero")]
+//!     amount: u64,
+//! }
+//!
+//! let payment = Payment { payee: "acme".to_string(), amount: 1250 };
+//! let mut buffer: Vec<u8> = Vec::with_capacity(Payment::WIDTH);
+//! payment.to_fixed_width(&mut buffer).unwrap();
+//! assert_eq!(b"acme    001250", buffer.as_slice());
+//!
+//! assert_eq!(Ok(payment), Payment::from_fixed_width(&buffer));
+//! # }
+//! ```
+//!
+
+use crate::{Alignment, PadderError, Source, Symbol, Unpad};
+
+/// A type that can be written to, and read from, a single fixed-width field.
+pub trait FixedWidthField: Sized {
+    /// Pad the value to the width of the field and push it to the buffer, truncating
+    /// text that is wider than the field without splitting a character.
+    ///
+    /// Returns an error if a number is wider than the field, or filled with digits other
+    /// than right-aligned zeros, since either would change its value.
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError>;
+
+    /// Strip the padding from the field and parse its value.
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError>;
+}
+
+/// A struct that can be written to, and read from, a fixed-width record.
+pub trait FixedWidth: Sized {
+    /// The total width of a record.
+    const WIDTH: usize;
+
+    /// Write every field of the struct as a record and push it to the buffer.
+    ///
+    /// Returns an error naming the first field that could not be written, in which case
+    /// nothing is pushed to the buffer.
+    fn to_fixed_width(&self, buffer: &mut Vec<u8>) -> Result<(), PadderError>;
+
+    /// Read a struct from a record, which has to be exactly [`FixedWidth::WIDTH`] long.
+    fn from_fixed_width(record: &[u8]) -> Result<Self, PadderError>;
+}
+
+/// Strip the padding from a field holding UTF-8 text.
+fn unpad_str(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<&str, PadderError> {
+    std::str::from_utf8(field)
+        .map(|text| text.unpad(mode, symbol))
+        .map_err(|_| PadderError::InvalidValue("field is not valid UTF-8".to_string()))
+}
+
+/// Slice the text to at most the width in bytes, keeping the part that the alignment
+/// keeps when truncating, without splitting a character.
+fn slice_str(text: &str, width: usize, mode: Alignment) -> &str {
+    if text.len() <= width {
+        return text;
+    }
+
+    let (mut start, mut end) = match mode {
+        Alignment::Left => (0, width),
+        Alignment::Right => (text.len() - width, text.len()),
+        Alignment::Center => (
+            text.len() / 2 - width / 2,
+            text.len() / 2 + width / 2 + width % 2,
+        ),
+    };
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    while end > start && !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[start..end]
+}
+
+/// Pad a formatted number to the width of the field and push it to the buffer.
+fn write_number(
+    text: &str,
+    width: usize,
+    mode: Alignment,
+    symbol: Symbol,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    let fill: u8 = symbol.into();
+    if fill.is_ascii_digit() && !(mode == Alignment::Right && symbol == Symbol::Zero) {
+        return Err(PadderError::InvalidValue(
+            "numbers filled with digits need right alignment and zeros".to_string(),
+        ));
+    }
+    if text.len() > width {
+        return Err(PadderError::Overflow {
+            width,
+            required: text.len(),
+        });
+    }
+    text.as_bytes()
+        .pad_and_push_to_buffer(width, mode, symbol, buffer);
+    Ok(())
+}
+
+impl FixedWidthField for String {
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        slice_str(self, width, mode)
+            .as_bytes()
+            .pad_and_push_to_buffer(width, mode, symbol, buffer);
+        Ok(())
+    }
+
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError> {
+        unpad_str(field, mode, symbol).map(str::to_string)
+    }
+}
+
+impl FixedWidthField for char {
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        let mut scratch = [0u8; 4];
+        slice_str(self.encode_utf8(&mut scratch), width, mode)
+            .as_bytes()
+            .pad_and_push_to_buffer(width, mode, symbol, buffer);
+        Ok(())
+    }
+
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError> {
+        let text: &str = unpad_str(field, mode, symbol)?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            (None, _) if field.is_empty() => Err(PadderError::EmptyField),
+            (None, _) => Ok(char::from(symbol)),
+            _ => Err(PadderError::InvalidValue(format!(
+                "'{}' is not a single character",
+                text
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl FixedWidthField for $t {
+            fn write_field(
+                &self,
+                width: usize,
+                mode: Alignment,
+                symbol: Symbol,
+                buffer: &mut Vec<u8>,
+            ) -> Result<(), PadderError> {
+                write_number(&self.to_string(), width, mode, symbol, buffer)
+            }
+
+            fn read_field(
+                field: &[u8],
+                mode: Alignment,
+                symbol: Symbol,
+            ) -> Result<Self, PadderError> {
+                parse_number(field, mode, symbol)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl FixedWidthField for $t {
+            /// Negative numbers that are right-aligned and padded with zeros keep their
+            /// sign in front of the padding, like `-0042`.
+            fn write_field(
+                &self,
+                width: usize,
+                mode: Alignment,
+                symbol: Symbol,
+                buffer: &mut Vec<u8>,
+            ) -> Result<(), PadderError> {
+                let text: String = self.to_string();
+                if *self < 0 && mode == Alignment::Right && symbol == Symbol::Zero {
+                    if text.len() > width {
+                        return Err(PadderError::Overflow {
+                            width,
+                            required: text.len(),
+                        });
+                    }
+                    buffer.push(b'-');
+                    return write_number(&text[1..], width - 1, mode, symbol, buffer);
+                }
+                write_number(&text, width, mode, symbol, buffer)
+            }
+
+            fn read_field(
+                field: &[u8],
+                mode: Alignment,
+                symbol: Symbol,
+            ) -> Result<Self, PadderError> {
+                parse_number(field, mode, symbol)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Parse a padded number, where a field of only zeros is zero.
+fn parse_number<T: std::str::FromStr>(
+    field: &[u8],
+    mode: Alignment,
+    symbol: Symbol,
+) -> Result<T, PadderError> {
+    let text: &str = unpad_str(field, mode, symbol)?.trim();
+    let text: &str = match text {
+        "" if symbol == Symbol::Zero && !field.is_empty() => "0",
+        "" => return Err(PadderError::EmptyField),
+        text => text,
+    };
+    text.parse()
+        .map_err(|_| PadderError::InvalidValue(format!("'{}' is not a number", text)))
+}
+
+impl FixedWidthField for f64 {
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        write_number(&self.to_string(), width, mode, symbol, buffer)
+    }
+
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError> {
+        parse_number(field, mode, symbol)
+    }
+}
+
+impl FixedWidthField for f32 {
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        write_number(&self.to_string(), width, mode, symbol, buffer)
+    }
+
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError> {
+        parse_number(field, mode, symbol)
+    }
+}
+
+/// A missing value is written as a field of only the padding symbol, and a field of only
+/// the padding symbol or whitespace is read as a missing value. This means that a zero
+/// padded with [`Symbol::Zero`] is read back as a missing value as well.
+impl<T: FixedWidthField> FixedWidthField for Option<T> {
+    fn write_field(
+        &self,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), PadderError> {
+        match self {
+            Some(value) => value.write_field(width, mode, symbol, buffer),
+            None => {
+                "".as_bytes()
+                    .pad_and_push_to_buffer(width, mode, symbol, buffer);
+                Ok(())
+            }
+        }
+    }
+
+    fn read_field(field: &[u8], mode: Alignment, symbol: Symbol) -> Result<Self, PadderError> {
+        let fill: u8 = symbol.into();
+        if field.iter().all(|&b| b == fill || b.is_ascii_whitespace()) {
+            return Ok(None);
+        }
+        T::read_field(field, mode, symbol).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<T: FixedWidthField>(
+        value: T,
+        width: usize,
+        mode: Alignment,
+        symbol: Symbol,
+    ) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        value.write_field(width, mode, symbol, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn write_fields() {
+        assert_eq!(
+            "ab  ",
+            write("ab".to_string(), 4, Alignment::Left, Symbol::Whitespace)
+        );
+        assert_eq!("0042", write(42u32, 4, Alignment::Right, Symbol::Zero));
+        assert_eq!("-042", write(-42i64, 4, Alignment::Right, Symbol::Zero));
+        assert_eq!(
+            "-42 ",
+            write(-42i64, 4, Alignment::Left, Symbol::Whitespace)
+        );
+        assert_eq!(
+            "  2.5",
+            write(2.5f64, 5, Alignment::Right, Symbol::Whitespace)
+        );
+        assert_eq!("x", write('x', 1, Alignment::Left, Symbol::Whitespace));
+        assert_eq!(
+            "   ",
+            write(None::<u8>, 3, Alignment::Right, Symbol::Whitespace)
+        );
+        assert_eq!(
+            "abc",
+            write("abcdef".to_string(), 3, Alignment::Left, Symbol::Whitespace)
+        );
+    }
+
+    #[test]
+    fn read_fields() {
+        assert_eq!(
+            Ok(42u32),
+            u32::read_field(b"0042", Alignment::Right, Symbol::Zero)
+        );
+        assert_eq!(
+            Ok(0u32),
+            u32::read_field(b"0000", Alignment::Right, Symbol::Zero)
+        );
+        assert_eq!(
+            Ok(-42i16),
+            i16::read_field(b"-042", Alignment::Right, Symbol::Zero)
+        );
+        assert_eq!(
+            Ok(-42i16),
+            i16::read_field(b"-42 ", Alignment::Left, Symbol::Whitespace)
+        );
+        assert_eq!(
+            Ok("ab".to_string()),
+            String::read_field(b"ab  ", Alignment::Left, Symbol::Whitespace)
+        );
+        assert_eq!(
+            Ok(None),
+            Option::<u8>::read_field(b"   ", Alignment::Right, Symbol::Zero)
+        );
+        assert_eq!(
+            Ok(Some(7u8)),
+            Option::<u8>::read_field(b"007", Alignment::Right, Symbol::Zero)
+        );
+        assert_eq!(
+            Ok(' '),
+            char::read_field(b" ", Alignment::Left, Symbol::Whitespace)
+        );
+    }
+
+    #[test]
+    fn read_invalid_fields() {
+        assert_eq!(
+            Err(PadderError::EmptyField),
+            u8::read_field(b"   ", Alignment::Right, Symbol::Whitespace)
+        );
+        assert!(matches!(
+            u8::read_field(b"300", Alignment::Right, Symbol::Zero),
+            Err(PadderError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            String::read_field(&[0xFF, 0x20], Alignment::Left, Symbol::Whitespace),
+            Err(PadderError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn write_overflowing_numbers() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = 1234567u32.write_field(6, Alignment::Right, Symbol::Zero, &mut buffer);
+        let overflow = PadderError::Overflow {
+            width: 6,
+            required: 7,
+        };
+        assert_eq!(Err(overflow.clone()), output);
+        let output = (-123456i64).write_field(6, Alignment::Right, Symbol::Zero, &mut buffer);
+        assert_eq!(Err(overflow), output);
+        let output = 2.25f64.write_field(3, Alignment::Left, Symbol::Whitespace, &mut buffer);
+        assert!(matches!(output, Err(PadderError::Overflow { .. })));
+        assert!(buffer.is_empty());
+        assert_eq!(
+            "-12345",
+            write(-12345i64, 6, Alignment::Right, Symbol::Zero)
+        );
+    }
+
+    #[test]
+    fn write_truncated_text_on_char_boundaries() {
+        assert_eq!(
+            "åä ",
+            write("åäö".to_string(), 5, Alignment::Left, Symbol::Whitespace)
+        );
+        assert_eq!(
+            " äö",
+            write("åäö".to_string(), 5, Alignment::Right, Symbol::Whitespace)
+        );
+        assert_eq!(
+            "ä ",
+            write("åäö".to_string(), 3, Alignment::Center, Symbol::Whitespace)
+        );
+        assert_eq!("-", write('é', 1, Alignment::Left, Symbol::Hyphen));
+    }
+
+    #[test]
+    fn write_digit_filled_numbers() {
+        let mut buffer: Vec<u8> = Vec::new();
+        for mode in [Alignment::Left, Alignment::Center] {
+            let output = 120u32.write_field(5, mode, Symbol::Zero, &mut buffer);
+            assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+        }
+        let output = 12i32.write_field(5, Alignment::Right, Symbol::Nine, &mut buffer);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+        let output = (-12i32).write_field(5, Alignment::Left, Symbol::Zero, &mut buffer);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn option_round_trip() {
+        let mut buffer: Vec<u8> = Vec::new();
+        None::<u16>
+            .write_field(4, Alignment::Right, Symbol::Zero, &mut buffer)
+            .unwrap();
+        assert_eq!(b"0000", buffer.as_slice());
+        assert_eq!(
+            Ok(None),
+            Option::<u16>::read_field(&buffer, Alignment::Right, Symbol::Zero)
+        );
+
+        buffer.clear();
+        Some(7u16)
+            .write_field(4, Alignment::Left, Symbol::Hyphen, &mut buffer)
+            .unwrap();
+        assert_eq!(
+            Ok(Some(7)),
+            Option::<u16>::read_field(&buffer, Alignment::Left, Symbol::Hyphen)
+        );
+    }
+}
@@ -0,0 +1,727 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Serialize any type implementing [`serde::Serialize`] as a fixed-width record.
+//!
+//! The [`Layout`] supplies the width, alignment, symbol and kind of every field, while
+//! the serialized struct supplies the values, matched to the fields of the layout by
+//! name. Fields of the layout that the struct does not have are written as missing
+//! values, which makes it easy to skip filler fields. Sequences and tuples are matched
+//! to the fields by position instead.
+//!
+//! ```
+//! use padder::*;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Payment {
+//!     payee: String,
+//!     amount: f64,
+//! }
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("payee", 8, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 7, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//!     Field::new("filler", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//! ]);
+//!
+//! let payment = Payment { payee: "acme".to_string(), amount: 12.5 };
+//! let mut buffer: Vec<u8> = Vec::with_capacity(layout.width());
+//! to_record(&payment, &layout, &mut buffer).unwrap();
+//! assert_eq!(b"acme    0001250  ", buffer.as_slice());
+//! ```
+//!
+
+use std::borrow::Cow;
+
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::layout::Layout;
+use crate::value::Value;
+use crate::PadderError;
+
+/// Serialize the value as a record with the given layout and push it to the buffer.
+///
+/// If the value fails to be serialized, an error is returned and the buffer is left
+/// untouched.
+pub fn to_record<T: Serialize + ?Sized>(
+    value: &T,
+    layout: &Layout,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    value.serialize(RecordSerializer::new(layout, buffer))
+}
+
+/// A [`serde::Serializer`] that writes a struct, map, sequence or tuple as one record
+/// with the fields of a [`Layout`].
+pub struct RecordSerializer<'a> {
+    layout: &'a Layout,
+    buffer: &'a mut Vec<u8>,
+}
+
+impl<'a> RecordSerializer<'a> {
+    /// Create a new [`RecordSerializer`] that pushes the record to the buffer.
+    pub fn new(layout: &'a Layout, buffer: &'a mut Vec<u8>) -> Self {
+        Self { layout, buffer }
+    }
+
+    /// Start collecting the values of a record.
+    fn compound(self) -> RecordCompound<'a> {
+        let count: usize = self.layout.fields().len();
+        RecordCompound {
+            layout: self.layout,
+            buffer: self.buffer,
+            values: vec![None; count],
+            position: 0,
+            key: None,
+        }
+    }
+}
+
+/// Create the error for a value that cannot be serialized as a record.
+fn not_a_record(kind: &str) -> PadderError {
+    PadderError::InvalidValue(format!(
+        "expected a struct, map, sequence or tuple, found {}",
+        kind
+    ))
+}
+
+impl<'a> ser::Serializer for RecordSerializer<'a> {
+    type Ok = ();
+    type Error = PadderError;
+    type SerializeSeq = RecordCompound<'a>;
+    type SerializeTuple = RecordCompound<'a>;
+    type SerializeTupleStruct = RecordCompound<'a>;
+    type SerializeTupleVariant = Impossible<(), PadderError>;
+    type SerializeMap = RecordCompound<'a>;
+    type SerializeStruct = RecordCompound<'a>;
+    type SerializeStructVariant = Impossible<(), PadderError>;
+
+    fn serialize_bool(self, _: bool) -> Result<(), PadderError> {
+        Err(not_a_record("a boolean"))
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), PadderError> {
+        Err(not_a_record("an integer"))
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), PadderError> {
+        Err(not_a_record("an integer"))
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), PadderError> {
+        Err(not_a_record("a float"))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), PadderError> {
+        self.serialize_u64(value as u64)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), PadderError> {
+        self.serialize_u64(value as u64)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), PadderError> {
+        self.serialize_u64(value as u64)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), PadderError> {
+        self.serialize_f64(value as f64)
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), PadderError> {
+        Err(not_a_record("a character"))
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), PadderError> {
+        Err(not_a_record("a string"))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), PadderError> {
+        Err(not_a_record("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), PadderError> {
+        Err(not_a_record("none"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), PadderError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), PadderError> {
+        Err(not_a_record("a unit"))
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), PadderError> {
+        Err(not_a_record("a unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), PadderError> {
+        Err(not_a_record("an enum"))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), PadderError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), PadderError> {
+        Err(not_a_record("an enum"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<RecordCompound<'a>, PadderError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<RecordCompound<'a>, PadderError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<RecordCompound<'a>, PadderError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, PadderError> {
+        Err(not_a_record("an enum"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<RecordCompound<'a>, PadderError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<RecordCompound<'a>, PadderError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, PadderError> {
+        Err(not_a_record("an enum"))
+    }
+}
+
+/// Collects the values of one record, by name or by position, and writes the record
+/// once all values have been serialized.
+pub struct RecordCompound<'a> {
+    layout: &'a Layout,
+    buffer: &'a mut Vec<u8>,
+    values: Vec<Option<Value<'static>>>,
+    position: usize,
+    key: Option<String>,
+}
+
+impl RecordCompound<'_> {
+    /// Set the value of the field with the given name.
+    fn set<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<(), PadderError> {
+        let index: usize = self.layout.position(name).ok_or_else(|| {
+            PadderError::InvalidValue(format!("'{}' is not a field of the layout", name))
+        })?;
+        let value: Value<'static> =
+            value
+                .serialize(ValueSerializer)
+                .map_err(|error| PadderError::Field {
+                    name: name.to_string(),
+                    error: Box::new(error),
+                })?;
+        self.values[index] = Some(value);
+        Ok(())
+    }
+
+    /// Set the value of the next field by position.
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PadderError> {
+        let count: usize = self.values.len();
+        if self.position >= count {
+            return Err(PadderError::FieldCount {
+                expected: count,
+                found: self.position + 1,
+            });
+        }
+        let value: Value<'static> = value.serialize(ValueSerializer)?;
+        self.values[self.position] = Some(value);
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Write the record, where fields without a value are written as missing values.
+    fn finish(self) -> Result<(), PadderError> {
+        let values: Vec<Value> = self
+            .values
+            .into_iter()
+            .map(|v| v.unwrap_or(Value::Null))
+            .collect();
+        self.layout.write(&values, self.buffer)
+    }
+}
+
+impl ser::SerializeSeq for RecordCompound<'_> {
+    type Ok = ();
+    type Error = PadderError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PadderError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), PadderError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for RecordCompound<'_> {
+    type Ok = ();
+    type Error = PadderError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PadderError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), PadderError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for RecordCompound<'_> {
+    type Ok = ();
+    type Error = PadderError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PadderError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), PadderError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for RecordCompound<'_> {
+    type Ok = ();
+    type Error = PadderError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), PadderError> {
+        self.key = Some(key.serialize(ValueSerializer)?.to_string());
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PadderError> {
+        let key: String = self
+            .key
+            .take()
+            .ok_or_else(|| PadderError::InvalidValue("map value without a key".to_string()))?;
+        self.set(&key, value)
+    }
+
+    fn end(self) -> Result<(), PadderError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for RecordCompound<'_> {
+    type Ok = ();
+    type Error = PadderError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), PadderError> {
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<(), PadderError> {
+        self.finish()
+    }
+}
+
+/// A [`serde::Serializer`] for the value of a single field.
+///
+/// Unit enum variants are serialized as their names, and anything that is not a single
+/// value, like a nested struct, is an error.
+struct ValueSerializer;
+
+/// Create the error for a value that cannot be written to a single field.
+fn not_a_value(kind: &str) -> PadderError {
+    PadderError::InvalidValue(format!("{} cannot be written to a single field", kind))
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value<'static>;
+    type Error = PadderError;
+    type SerializeSeq = Impossible<Value<'static>, PadderError>;
+    type SerializeTuple = Impossible<Value<'static>, PadderError>;
+    type SerializeTupleStruct = Impossible<Value<'static>, PadderError>;
+    type SerializeTupleVariant = Impossible<Value<'static>, PadderError>;
+    type SerializeMap = Impossible<Value<'static>, PadderError>;
+    type SerializeStruct = Impossible<Value<'static>, PadderError>;
+    type SerializeStructVariant = Impossible<Value<'static>, PadderError>;
+
+    fn serialize_bool(self, value: bool) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Boolean(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Integer(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value<'static>, PadderError> {
+        self.serialize_i64(value as i64)
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value<'static>, PadderError> {
+        i64::try_from(value)
+            .map(Value::Integer)
+            .map_err(|_| PadderError::OutOfRange)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value<'static>, PadderError> {
+        self.serialize_f64(value as f64)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Float(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Text(Cow::Owned(value.to_string())))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Text(Cow::Owned(value.to_string())))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Bytes(Cow::Owned(value.to_vec())))
+    }
+
+    fn serialize_none(self) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Value<'static>, PadderError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value<'static>, PadderError> {
+        Ok(Value::Text(Cow::Borrowed(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Value<'static>, PadderError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Value<'static>, PadderError> {
+        Err(not_a_value("an enum with data"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, PadderError> {
+        Err(not_a_value("a sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, PadderError> {
+        Err(not_a_value("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, PadderError> {
+        Err(not_a_value("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, PadderError> {
+        Err(not_a_value("an enum with data"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, PadderError> {
+        Err(not_a_value("a map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, PadderError> {
+        Err(not_a_value("a struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, PadderError> {
+        Err(not_a_value("an enum with data"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::{Alignment, BoolCodes, DateTimeFormat, Symbol};
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    enum Status {
+        Active,
+    }
+
+    #[derive(Serialize)]
+    struct Customer {
+        id: u32,
+        name: String,
+        balance: f64,
+        active: bool,
+        joined: String,
+        status: Status,
+        nickname: Option<String>,
+    }
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new("id", 5, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "balance",
+                8,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+            Field::new(
+                "active",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+            Field::new(
+                "joined",
+                8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::DateTime(DateTimeFormat::new("YYYYMMDD")),
+            ),
+            Field::new(
+                "status",
+                6,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "nickname",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "filler",
+                2,
+                Alignment::Left,
+                Symbol::Hyphen,
+                FieldKind::Text,
+            ),
+        ])
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let customer = Customer {
+            id: 42,
+            name: "Jane".to_string(),
+            balance: 1234.5,
+            active: true,
+            joined: "2024-01-31".to_string(),
+            status: Status::Active,
+            nickname: None,
+        };
+        let mut buffer: Vec<u8> = Vec::new();
+        to_record(&customer, &layout(), &mut buffer).unwrap();
+        assert_eq!(
+            b"00042Jane    00123450Y20240131Active    --".as_slice(),
+            buffer.as_slice()
+        );
+    }
+
+    #[test]
+    fn serialize_tuple_and_map() {
+        let layout = Layout::new(vec![
+            Field::new("a", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new("b", 3, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+        ]);
+
+        let mut buffer: Vec<u8> = Vec::new();
+        to_record(&(7, "xy"), &layout, &mut buffer).unwrap();
+        assert_eq!(b"007xy ", buffer.as_slice());
+
+        let mut map: BTreeMap<&str, &str> = BTreeMap::new();
+        map.insert("b", "z");
+        let mut buffer: Vec<u8> = Vec::new();
+        to_record(&map, &layout, &mut buffer).unwrap();
+        assert_eq!(b"000z  ", buffer.as_slice());
+    }
+
+    #[test]
+    fn serialize_errors() {
+        let layout = Layout::new(vec![Field::new(
+            "a",
+            2,
+            Alignment::Right,
+            Symbol::Zero,
+            FieldKind::Numeric,
+        )]);
+        let mut buffer: Vec<u8> = Vec::new();
+
+        let output = to_record(&5, &layout, &mut buffer);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+
+        let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+        map.insert("b", 1);
+        let output = to_record(&map, &layout, &mut buffer);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+
+        let output = to_record(&(1, 2), &layout, &mut buffer);
+        assert!(matches!(output, Err(PadderError::FieldCount { .. })));
+
+        let output = to_record(&[(100,)], &layout, &mut buffer);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+
+        let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+        map.insert("a", 100);
+        let output = to_record(&map, &layout, &mut buffer);
+        assert!(matches!(output, Err(PadderError::Field { .. })));
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,433 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Plain-text tables of aligned columns.
+//!
+//! A [`Table`] is built from optional headers and rows of cells, and is rendered with
+//! every column as wide as its widest cell, see [`AutoFit`]. Each cell is padded with
+//! the [`Alignment`] and [`Symbol`] of its column, and the columns are separated by a
+//! separator, or drawn inside a [`Border`] of ASCII or box-drawing characters.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let table = Table::new()
+//!     .with_headers(["id", "name", "amount"])
+//!     .with_row(["7", "Ström", "12.50"])
+//!     .with_row(["12", "Lind", "3.00"])
+//!     .with_column_alignment(0, Alignment::Right)
+//!     .with_column_alignment(2, Alignment::Right)
+//!     .with_border(Border::Box);
+//!
+//! let expected = "\
+//! ┌────┬───────┬────────┐
+//! │ id │ name  │ amount │
+//! ├────┼───────┼────────┤
+//! │  7 │ Ström │  12.50 │
+//! │ 12 │ Lind  │   3.00 │
+//! └────┴───────┴────────┘
+//! ";
+//! assert_eq!(expected, table.render());
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use crate::fit::{AutoFit, Fit, Measure};
+use crate::{Alignment, Source, Symbol};
+
+/// Exhaustive enum for the borders drawn around and between the columns of a table.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Border {
+    /// No border, where the columns are only separated by the separator of the table.
+    #[default]
+    None,
+    /// A border of `+`, `-` and `|`.
+    Ascii,
+    /// A border of box-drawing characters, like `┌`, `─` and `│`.
+    Box,
+}
+
+/// The characters of a border, where the corners of each rule are the left, middle
+/// and right ones.
+struct Lines {
+    horizontal: &'static str,
+    vertical: &'static str,
+    top: [&'static str; 3],
+    middle: [&'static str; 3],
+    bottom: [&'static str; 3],
+}
+
+impl Border {
+    /// Get the characters of the border, or [`None`] if there is no border.
+    fn lines(&self) -> Option<Lines> {
+        match self {
+            Self::None => None,
+            Self::Ascii => Some(Lines {
+                horizontal: "-",
+                vertical: "|",
+                top: ["+", "+", "+"],
+                middle: ["+", "+", "+"],
+                bottom: ["+", "+", "+"],
+            }),
+            Self::Box => Some(Lines {
+                horizontal: "─",
+                vertical: "│",
+                top: ["┌", "┬", "┐"],
+                middle: ["├", "┼", "┤"],
+                bottom: ["└", "┴", "┘"],
+            }),
+        }
+    }
+}
+
+/// A builder of plain-text tables with aligned columns.
+#[derive(Debug, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    alignment: Alignment,
+    symbol: Symbol,
+    /// The alignment and symbol of each column, where [`None`] falls back to those of
+    /// all columns.
+    columns: Vec<(Option<Alignment>, Option<Symbol>)>,
+    separator: String,
+    border: Border,
+    underline: bool,
+    measure: Measure,
+    max_width: Option<usize>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            headers: Vec::new(),
+            rows: Vec::new(),
+            alignment: Alignment::Left,
+            symbol: Symbol::Whitespace,
+            columns: Vec::new(),
+            separator: " ".to_string(),
+            border: Border::default(),
+            underline: true,
+            measure: Measure::default(),
+            max_width: None,
+        }
+    }
+}
+
+impl Table {
+    /// Create a new empty [`Table`] without a border, where the columns are left-aligned,
+    /// padded with whitespace and separated by a single space, and the headers are
+    /// underlined.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the headers of the columns.
+    pub fn with_headers<I: IntoIterator<Item = T>, T: ToString>(mut self, headers: I) -> Self {
+        self.headers = headers.into_iter().map(|h| h.to_string()).collect();
+        self
+    }
+
+    /// Add a row of cells, which may have fewer cells than the other rows.
+    pub fn with_row<I: IntoIterator<Item = T>, T: ToString>(mut self, row: I) -> Self {
+        self.rows
+            .push(row.into_iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Add every row of cells, see [`Table::with_row`].
+    pub fn with_rows<I, R, T>(self, rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: ToString,
+    {
+        rows.into_iter()
+            .fold(self, |table, row| table.with_row(row))
+    }
+
+    /// Set the alignment of all columns without an alignment of their own.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the symbol to pad the cells of all columns without a symbol of their own.
+    pub fn with_symbol(mut self, symbol: Symbol) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Get the alignment and symbol of the column with the given index, resizing the
+    /// columns to include it.
+    fn column_mut(&mut self, column: usize) -> &mut (Option<Alignment>, Option<Symbol>) {
+        if self.columns.len() <= column {
+            self.columns.resize(column + 1, (None, None));
+        }
+        &mut self.columns[column]
+    }
+
+    /// Set the alignment of the column with the given index, including its header.
+    pub fn with_column_alignment(mut self, column: usize, alignment: Alignment) -> Self {
+        self.column_mut(column).0 = Some(alignment);
+        self
+    }
+
+    /// Set the symbol to pad the cells of the column with the given index with, where
+    /// the header is always padded with whitespace.
+    pub fn with_column_symbol(mut self, column: usize, symbol: Symbol) -> Self {
+        self.column_mut(column).1 = Some(symbol);
+        self
+    }
+
+    /// Set the separator between the columns of a table without a border.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Set the border drawn around and between the columns.
+    pub fn with_border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set whether a line is drawn between the headers and the rows.
+    pub fn with_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Set how the width of the cells is measured.
+    pub fn with_measure(mut self, measure: Measure) -> Self {
+        self.measure = measure;
+        self
+    }
+
+    /// Cap the width of every column, truncating wider cells according to the alignment
+    /// of their column.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Get the alignment and symbol of the column with the given index.
+    fn style(&self, column: usize) -> (Alignment, Symbol) {
+        let (alignment, symbol) = self.columns.get(column).copied().unwrap_or_default();
+        (
+            alignment.unwrap_or(self.alignment),
+            symbol.unwrap_or(self.symbol),
+        )
+    }
+
+    /// Push a row of cells padded to the widths of the columns, where missing cells are
+    /// blank, like the header, rather than filled with the symbol of their column.
+    fn push_row(&self, output: &mut String, row: &[String], widths: &[usize], header: bool) {
+        let lines: Option<Lines> = self.border.lines();
+        let separator: String = match &lines {
+            Some(lines) => format!(" {} ", lines.vertical),
+            None => self.separator.clone(),
+        };
+
+        if let Some(lines) = &lines {
+            output.push_str(lines.vertical);
+            output.push(' ');
+        }
+        for (column, width) in widths.iter().enumerate() {
+            if column > 0 {
+                output.push_str(&separator);
+            }
+            let (alignment, symbol) = self.style(column);
+            let (cell, symbol): (&str, Symbol) = match row.get(column) {
+                Some(cell) if !header => (cell, symbol),
+                Some(cell) => (cell, Symbol::Whitespace),
+                None => ("", Symbol::Whitespace),
+            };
+            output.push_str(&cell.fit(*width, alignment, symbol, self.measure));
+        }
+        if let Some(lines) = &lines {
+            output.push(' ');
+            output.push_str(lines.vertical);
+        }
+        output.push('\n');
+    }
+
+    /// Push a horizontal rule with the corners of the border, or of hyphens joined by the
+    /// separator if there is no border.
+    fn push_rule(&self, output: &mut String, widths: &[usize], corners: Option<[&str; 3]>) {
+        let Some(lines) = self.border.lines() else {
+            let rules: Vec<String> = widths
+                .iter()
+                .map(|&w| "".pad(w, Alignment::Left, Symbol::Hyphen))
+                .collect();
+            output.push_str(&rules.join(&self.separator));
+            output.push('\n');
+            return;
+        };
+
+        let [left, middle, right] = corners.unwrap_or(lines.middle);
+        output.push_str(left);
+        for (column, width) in widths.iter().enumerate() {
+            if column > 0 {
+                output.push_str(middle);
+            }
+            output.push_str(&lines.horizontal.repeat(width + 2));
+        }
+        output.push_str(right);
+        output.push('\n');
+    }
+
+    /// Render the table, with every line ending in a line feed.
+    pub fn render(&self) -> String {
+        let cells: Vec<Vec<&str>> = std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|row| row.iter().map(String::as_str).collect())
+            .collect();
+        let mut fit = AutoFit::new().with_measure(self.measure);
+        if let Some(max_width) = self.max_width {
+            fit = fit.with_max_width(max_width);
+        }
+        let widths: Vec<usize> = fit.widths(&cells);
+        if widths.is_empty() {
+            return String::new();
+        }
+
+        let lines: Option<Lines> = self.border.lines();
+        let mut output = String::new();
+        if let Some(lines) = &lines {
+            self.push_rule(&mut output, &widths, Some(lines.top));
+        }
+        if !self.headers.is_empty() {
+            self.push_row(&mut output, &self.headers, &widths, true);
+            if self.underline {
+                self.push_rule(&mut output, &widths, None);
+            }
+        }
+        for row in &self.rows {
+            self.push_row(&mut output, row, &widths, false);
+        }
+        if let Some(lines) = &lines {
+            self.push_rule(&mut output, &widths, Some(lines.bottom));
+        }
+        output
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        Table::new()
+            .with_headers(["id", "name"])
+            .with_rows([vec!["7", "a"], vec!["123", "bcdef"], vec!["4"]])
+            .with_column_alignment(0, Alignment::Right)
+            .with_column_symbol(0, Symbol::Zero)
+    }
+
+    #[test]
+    fn render_without_border() {
+        let expected = concat!(
+            " id name \n",
+            "--- -----\n",
+            "007 a    \n",
+            "123 bcdef\n",
+            "004      \n",
+        );
+        assert_eq!(expected, table().render());
+
+        let output: String = table()
+            .with_separator(" : ")
+            .with_underline(false)
+            .with_max_width(3)
+            .to_string();
+        let expected = concat!(" id : nam\n", "007 : a  \n", "123 : bcd\n", "004 :    \n");
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn render_missing_cells() {
+        let output: String = Table::new()
+            .with_rows([vec!["a", "1"], vec!["b"]])
+            .with_column_alignment(1, Alignment::Right)
+            .with_column_symbol(1, Symbol::Zero)
+            .render();
+        assert_eq!("a 1\nb  \n", output);
+    }
+
+    #[test]
+    fn render_ascii_border() {
+        let expected = concat!(
+            "+-----+-------+\n",
+            "|  id | name  |\n",
+            "+-----+-------+\n",
+            "| 007 | a     |\n",
+            "| 123 | bcdef |\n",
+            "| 004 |       |\n",
+            "+-----+-------+\n",
+        );
+        assert_eq!(expected, table().with_border(Border::Ascii).render());
+
+        let output: String = Table::new()
+            .with_row([1, 22])
+            .with_alignment(Alignment::Center)
+            .with_symbol(Symbol::Dot)
+            .with_border(Border::Ascii)
+            .render();
+        assert_eq!("+---+----+\n| 1 | 22 |\n+---+----+\n", output);
+    }
+
+    #[test]
+    fn render_box_border() {
+        let output: String = Table::new()
+            .with_headers(["å", "b"])
+            .with_row(["xyz", "ö"])
+            .with_border(Border::Box)
+            .with_underline(false)
+            .render();
+        let expected = concat!(
+            "┌─────┬───┐\n",
+            "│ å   │ b │\n",
+            "│ xyz │ ö │\n",
+            "└─────┴───┘\n",
+        );
+        assert_eq!(expected, output);
+        assert_eq!("", Table::new().with_border(Border::Box).render());
+    }
+}
@@ -0,0 +1,721 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Validation of fixed-width files against a layout, before they are sent anywhere.
+//!
+//! Where a [`FixedWidthReader`](crate::FixedWidthReader) stops at the first record that
+//! cannot be decoded, a [`Validator`] checks every record and collects all problems in a
+//! [`Report`], each with the line, column and field where it was found. Besides the
+//! record length and the decoding of each field, the validator checks the characters
+//! of padded fields more strictly than decoding does: numeric fields may only contain
+//! digits, and the fill symbol must sit on the side of the value that the alignment of
+//! the field says it does. With a header and trailer, the record count and totals of
+//! the trailer are checked against the records of the file.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("id", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+//!     Field::new("name", 5, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//! ]);
+//! let validator = Validator::new(layout);
+//!
+//! let report: Report = validator.validate(b"007alice\n0x9bob  \n012  eve\n");
+//! assert_eq!(3, report.records);
+//! assert_eq!(2, report.invalid);
+//! assert_eq!(
+//!     "line 2, column 2, field 'id': invalid character 'x'",
+//!     report.issues[0].to_string(),
+//! );
+//! assert_eq!(
+//!     "line 3, column 4, field 'name': fill symbol on the wrong side of the value",
+//!     report.issues[1].to_string(),
+//! );
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+use crate::layout::{Field, FieldKind, Layout};
+use crate::value::Value;
+use crate::writer::{Terminator, TrailerField};
+use crate::{Alignment, PadderError};
+
+/// Exhaustive enum for the problems found when validating a fixed-width file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum IssueKind {
+    /// The length of the record does not match the width of its layout.
+    RecordLength { expected: usize, found: usize },
+    /// The byte is not allowed in the field, like a letter in a numeric field.
+    InvalidCharacter(u8),
+    /// The fill symbol is on the wrong side of the value for the alignment of the field,
+    /// or a centered value is not in the center.
+    Misaligned,
+    /// The field cannot be decoded, with the message of the decoding error.
+    InvalidValue(String),
+    /// The header or trailer record is missing.
+    MissingRecord(String),
+    /// The record count of the trailer does not match the number of records.
+    RecordCount { expected: u64, found: String },
+    /// A constant or total of the trailer does not match the expected value.
+    TrailerValue { expected: String, found: String },
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RecordLength { expected, found } => write!(
+                f,
+                "record length is {} but the layout width is {}",
+                found, expected,
+            ),
+            Self::InvalidCharacter(byte) if byte.is_ascii_graphic() || *byte == b' ' => {
+                write!(f, "invalid character '{}'", *byte as char)
+            }
+            Self::InvalidCharacter(byte) => write!(f, "invalid character {:#04x}", byte),
+            Self::Misaligned => write!(f, "fill symbol on the wrong side of the value"),
+            Self::InvalidValue(message) => write!(f, "{}", message),
+            Self::MissingRecord(record) => write!(f, "missing {} record", record),
+            Self::RecordCount { expected, found } => write!(
+                f,
+                "record count is '{}' but the file has {} records",
+                found, expected,
+            ),
+            Self::TrailerValue { expected, found } => {
+                write!(f, "value is '{}' but expected '{}'", found, expected)
+            }
+        }
+    }
+}
+
+/// A single problem found when validating a fixed-width file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Issue {
+    /// The line of the record, or the index of the record for block formats, from 1.
+    pub line: u64,
+    /// The byte column in the record where the problem starts, from 1.
+    pub column: usize,
+    /// The name of the field, if the problem is in a single field.
+    pub field: Option<String>,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)?;
+        if let Some(field) = &self.field {
+            write!(f, ", field '{}'", field)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// The outcome of validating a fixed-width file.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Report {
+    /// The number of records, excluding the header and trailer.
+    pub records: u64,
+    /// The number of records with at least one issue, excluding the header and trailer.
+    pub invalid: u64,
+    /// Every issue found, in the order of the file.
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    /// Check whether the file has no issues at all.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Serialize the report as JSON, for other tools to consume.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, PadderError> {
+        serde_json::to_string(self).map_err(|e| PadderError::InvalidValue(e.to_string()))
+    }
+}
+
+/// Check a single raw field, returning the offset in the field and the kind of the
+/// first problem found.
+fn check_field(field: &Field, raw: &[u8]) -> Option<(usize, IssueKind)> {
+    let fill: u8 = field.symbol.into();
+    let padded: bool = matches!(
+        field.kind,
+        FieldKind::Text | FieldKind::Numeric | FieldKind::Boolean(_)
+    );
+
+    if padded && !raw.is_empty() {
+        let content: &[u8] = field.strip(raw);
+        // A digit fill is indistinguishable from the digits of the value itself.
+        if !content.is_empty() && !fill.is_ascii_alphanumeric() {
+            match field.alignment {
+                Alignment::Left if raw[0] == fill => return Some((0, IssueKind::Misaligned)),
+                Alignment::Right if raw[raw.len() - 1] == fill => {
+                    return Some((raw.len() - 1, IssueKind::Misaligned))
+                }
+                Alignment::Center => {
+                    let leading: usize = raw.iter().take_while(|&&b| b == fill).count();
+                    let (expected, _) = field
+                        .alignment
+                        .left_right_padding(raw.len() - content.len());
+                    if leading != expected {
+                        return Some((leading.min(expected), IssueKind::Misaligned));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if field.kind == FieldKind::Numeric {
+            let start: usize = match field.alignment {
+                Alignment::Left => 0,
+                _ => raw.iter().take_while(|&&b| b == fill).count(),
+            };
+            if let Some(i) = content.iter().position(|b| !b.is_ascii_digit()) {
+                return Some((start + i, IssueKind::InvalidCharacter(content[i])));
+            }
+        }
+    }
+
+    match field.decode(raw) {
+        Ok(_) => None,
+        Err(error) => Some((0, IssueKind::InvalidValue(error.to_string()))),
+    }
+}
+
+/// A validator of fixed-width files, with an optional header and trailer.
+#[derive(Debug, Clone)]
+pub struct Validator {
+    layout: Layout,
+    terminator: Terminator,
+    header: Option<Layout>,
+    trailer: Option<(Layout, Vec<TrailerField>)>,
+}
+
+impl Validator {
+    /// Create a new [`Validator`] of records with the layout, each followed by a line
+    /// feed or a carriage return and line feed.
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            terminator: Terminator::default(),
+            header: None,
+            trailer: None,
+        }
+    }
+
+    /// Set the terminator after each record, where [`Terminator::None`] splits the
+    /// records by the width of their layout.
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Expect a header record with its own layout before the records.
+    pub fn with_header(mut self, layout: Layout) -> Self {
+        self.header = Some(layout);
+        self
+    }
+
+    /// Expect a trailer record with its own layout after the records, whose fields are
+    /// checked against the values a [`FixedWidthWriter`](crate::FixedWidthWriter) would
+    /// write for them.
+    ///
+    /// Returns an error if the number of values does not match the trailer layout, or if
+    /// a total refers to a field that does not exist in the record layout.
+    pub fn with_trailer(
+        mut self,
+        layout: Layout,
+        fields: Vec<TrailerField>,
+    ) -> Result<Self, PadderError> {
+        if fields.len() != layout.fields().len() {
+            return Err(PadderError::FieldCount {
+                expected: layout.fields().len(),
+                found: fields.len(),
+            });
+        }
+        for field in &fields {
+            if let TrailerField::Total(name) = field {
+                if self.layout.position(name).is_none() {
+                    return Err(PadderError::InvalidLayout(format!(
+                        "total of '{}', which is not a field of the layout",
+                        name
+                    )));
+                }
+            }
+        }
+
+        self.trailer = Some((layout, fields));
+        Ok(self)
+    }
+
+    /// Split the data into records, each with its line, skipping empty lines.
+    fn records<'a>(&self, data: &'a [u8]) -> Vec<(u64, &'a [u8])> {
+        match self.terminator {
+            Terminator::Lf | Terminator::CrLf => data
+                .split(|&b| b == b'\n')
+                .zip(1u64..)
+                .map(|(line, number)| (number, line.strip_suffix(b"\r").unwrap_or(line)))
+                .filter(|(_, line)| !line.is_empty())
+                .collect(),
+            Terminator::None => {
+                let mut data: &[u8] = data;
+                let mut head: Option<&[u8]> = None;
+                let mut tail: Option<&[u8]> = None;
+                if let Some(header) = &self.header {
+                    let (record, rest) = data.split_at(header.width().min(data.len()));
+                    head = Some(record).filter(|r| !r.is_empty());
+                    data = rest;
+                }
+                if let Some((trailer, _)) = &self.trailer {
+                    let (rest, record) = data.split_at(data.len().saturating_sub(trailer.width()));
+                    tail = Some(record).filter(|r| !r.is_empty());
+                    data = rest;
+                }
+
+                let body = data.chunks(self.layout.width().max(1));
+                head.into_iter()
+                    .chain(body)
+                    .chain(tail)
+                    .zip(1u64..)
+                    .map(|(record, number)| (number, record))
+                    .collect()
+            }
+        }
+    }
+
+    /// Check a record against a layout, adding its issues to the report and returning
+    /// whether it is valid.
+    fn check_record(layout: &Layout, line: u64, record: &[u8], issues: &mut Vec<Issue>) -> bool {
+        let raw: Vec<&[u8]> = match layout.split(record) {
+            Ok(raw) => raw,
+            Err(_) => {
+                issues.push(Issue {
+                    line,
+                    column: layout.width().min(record.len()) + 1,
+                    field: None,
+                    kind: IssueKind::RecordLength {
+                        expected: layout.width(),
+                        found: record.len(),
+                    },
+                });
+                return false;
+            }
+        };
+
+        let count: usize = issues.len();
+        for ((field, raw), offset) in layout.fields().iter().zip(raw).zip(layout.offsets()) {
+            if let Some((position, kind)) = check_field(field, raw) {
+                issues.push(Issue {
+                    line,
+                    column: offset + position + 1,
+                    field: Some(field.name.clone()),
+                    kind,
+                });
+            }
+        }
+        issues.len() == count
+    }
+
+    /// Check the fields of the trailer against the record count and totals of the file.
+    ///
+    /// Totals are only checked when every record is valid, since the total of a file
+    /// with invalid records cannot be known.
+    fn check_trailer(
+        &self,
+        trailer: &Layout,
+        fields: &[TrailerField],
+        line: u64,
+        record: &[u8],
+        report: &mut Report,
+        totals: &[i64],
+    ) {
+        let Ok(values) = trailer.decode(record) else {
+            return;
+        };
+        let offsets: Vec<usize> = trailer.offsets();
+
+        let mut total: usize = 0;
+        for (index, field) in fields.iter().enumerate() {
+            let found: &Value = &values[index];
+            let kind: Option<IssueKind> = match field {
+                TrailerField::Value(value) => {
+                    (value.to_string() != found.to_string()).then(|| IssueKind::TrailerValue {
+                        expected: value.to_string(),
+                        found: found.to_string(),
+                    })
+                }
+                TrailerField::RecordCount => (found.to_scaled(0)
+                    != Ok(Some(report.records as i64)))
+                .then(|| IssueKind::RecordCount {
+                    expected: report.records,
+                    found: found.to_string(),
+                }),
+                TrailerField::Total(name) => {
+                    let expected: i64 = totals.get(total).copied().unwrap_or(0);
+                    total += 1;
+                    let scale: u32 = self.layout.field(name).map_or(0, |f| f.scale);
+                    (report.invalid == 0 && found.to_scaled(scale) != Ok(Some(expected))).then(
+                        || IssueKind::TrailerValue {
+                            expected: Value::Decimal(expected, scale).to_string(),
+                            found: found.to_string(),
+                        },
+                    )
+                }
+            };
+
+            if let Some(kind) = kind {
+                report.issues.push(Issue {
+                    line,
+                    column: offsets[index] + 1,
+                    field: Some(trailer.fields()[index].name.clone()),
+                    kind,
+                });
+            }
+        }
+    }
+
+    /// Validate every record of the data, returning a report of all issues found.
+    pub fn validate(&self, data: &[u8]) -> Report {
+        let mut records: Vec<(u64, &[u8])> = self.records(data);
+        let mut report = Report::default();
+
+        let missing = |line: u64, record: &str| Issue {
+            line,
+            column: 1,
+            field: None,
+            kind: IssueKind::MissingRecord(record.to_string()),
+        };
+        if let Some(header) = &self.header {
+            if records.is_empty() {
+                report.issues.push(missing(1, "header"));
+            } else {
+                let (line, record) = records.remove(0);
+                Self::check_record(header, line, record, &mut report.issues);
+            }
+        }
+        let trailer: Option<(u64, &[u8])> = match &self.trailer {
+            Some(_) => records.pop(),
+            None => None,
+        };
+
+        let names: Vec<&str> = match &self.trailer {
+            Some((_, fields)) => fields
+                .iter()
+                .filter_map(|f| match f {
+                    TrailerField::Total(name) => Some(name.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        let mut totals: Vec<i64> = vec![0; names.len()];
+
+        for &(line, record) in &records {
+            report.records += 1;
+            if !Self::check_record(&self.layout, line, record, &mut report.issues) {
+                report.invalid += 1;
+                continue;
+            }
+            for (name, total) in names.iter().zip(totals.iter_mut()) {
+                let value: i64 = self
+                    .layout
+                    .position(name)
+                    .and_then(|i| {
+                        let raw: &[u8] = self.layout.split(record).ok()?[i];
+                        let field: &Field = &self.layout.fields()[i];
+                        field.decode(raw).ok()?.to_scaled(field.scale).ok()?
+                    })
+                    .unwrap_or(0);
+                *total = total.saturating_add(value);
+            }
+        }
+
+        if let Some((layout, fields)) = &self.trailer {
+            match trailer {
+                Some((line, record)) => {
+                    if Self::check_record(layout, line, record, &mut report.issues) {
+                        self.check_trailer(layout, fields, line, record, &mut report, &totals);
+                    }
+                }
+                None => {
+                    let line: u64 = records.last().map_or(1, |(line, _)| line + 1);
+                    report.issues.push(missing(line, "trailer"));
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BoolCodes, Symbol};
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new("id", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "name",
+                4,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "amount",
+                5,
+                Alignment::Right,
+                Symbol::Whitespace,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+            Field::new(
+                "flag",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Boolean(BoolCodes::YesNo),
+            ),
+        ])
+    }
+
+    fn issue(line: u64, column: usize, field: Option<&str>, kind: IssueKind) -> Issue {
+        Issue {
+            line,
+            column,
+            field: field.map(str::to_string),
+            kind,
+        }
+    }
+
+    #[test]
+    fn valid_records() {
+        let report = Validator::new(layout()).validate(b"001ab    125Y\r\n\n002cd      7N\n");
+        assert!(report.is_valid());
+        assert_eq!(2, report.records);
+        assert_eq!(0, report.invalid);
+    }
+
+    #[test]
+    fn field_issues() {
+        let input: &[u8] = concat!(
+            "0a1ab    125Y\n",
+            "001 cd  125 Y\n",
+            "003ef    1 5X\n",
+            "004gh    125\n",
+        )
+        .as_bytes();
+        let report = Validator::new(layout()).validate(input);
+        assert_eq!(4, report.records);
+        assert_eq!(4, report.invalid);
+
+        let invalid = "unknown code 'X'".to_string();
+        let expected = vec![
+            issue(1, 2, Some("id"), IssueKind::InvalidCharacter(b'a')),
+            issue(2, 4, Some("name"), IssueKind::Misaligned),
+            issue(2, 12, Some("amount"), IssueKind::Misaligned),
+            issue(3, 11, Some("amount"), IssueKind::InvalidCharacter(b' ')),
+            issue(3, 13, Some("flag"), IssueKind::InvalidValue(invalid)),
+            issue(
+                4,
+                13,
+                None,
+                IssueKind::RecordLength {
+                    expected: 13,
+                    found: 12,
+                },
+            ),
+        ];
+        assert_eq!(expected, report.issues);
+        assert_eq!(
+            "line 4, column 13: record length is 12 but the layout width is 13",
+            report.issues[5].to_string()
+        );
+    }
+
+    #[test]
+    fn centered_fields() {
+        let layout = Layout::new(vec![Field::new(
+            "code",
+            6,
+            Alignment::Center,
+            Symbol::Hyphen,
+            FieldKind::Text,
+        )]);
+        let validator = Validator::new(layout);
+        assert!(validator
+            .validate(
+                b"--ab--
+-abc--
+------
+abcdef
+"
+            )
+            .is_valid());
+
+        let report = validator.validate(
+            b"-ab---
+---ab-
+",
+        );
+        let expected = vec![
+            issue(1, 2, Some("code"), IssueKind::Misaligned),
+            issue(2, 3, Some("code"), IssueKind::Misaligned),
+        ];
+        assert_eq!(expected, report.issues);
+    }
+
+    #[test]
+    fn header_and_trailer() {
+        let header = Layout::new(vec![Field::new(
+            "type",
+            2,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )]);
+        let trailer = Layout::new(vec![
+            Field::new(
+                "type",
+                2,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "count",
+                3,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            ),
+            Field::new(
+                "total",
+                6,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+        ]);
+        let fields = vec![
+            TrailerField::Value(Value::from("99")),
+            TrailerField::RecordCount,
+            TrailerField::Total("amount".to_string()),
+        ];
+        let validator = Validator::new(layout())
+            .with_header(header.clone())
+            .with_trailer(trailer.clone(), fields.clone())
+            .unwrap();
+
+        let input: &[u8] = b"00\n001ab    125Y\n002cd      7N\n99002000132\n";
+        assert!(validator.validate(input).is_valid());
+
+        let report = validator.validate(b"00\n001ab    125Y\n98003000130\n");
+        let expected = vec![
+            issue(
+                3,
+                1,
+                Some("type"),
+                IssueKind::TrailerValue {
+                    expected: "99".to_string(),
+                    found: "98".to_string(),
+                },
+            ),
+            issue(
+                3,
+                3,
+                Some("count"),
+                IssueKind::RecordCount {
+                    expected: 1,
+                    found: "3".to_string(),
+                },
+            ),
+            issue(
+                3,
+                6,
+                Some("total"),
+                IssueKind::TrailerValue {
+                    expected: "1.25".to_string(),
+                    found: "1.30".to_string(),
+                },
+            ),
+        ];
+        assert_eq!(expected, report.issues);
+
+        let report = validator.validate(b"");
+        let expected = vec![
+            issue(1, 1, None, IssueKind::MissingRecord("header".to_string())),
+            issue(1, 1, None, IssueKind::MissingRecord("trailer".to_string())),
+        ];
+        assert_eq!(expected, report.issues);
+
+        let validator = Validator::new(layout())
+            .with_terminator(Terminator::None)
+            .with_header(header)
+            .with_trailer(trailer.clone(), fields)
+            .unwrap();
+        let report = validator.validate(b"00001ab    125Y002cd      7N99002000132");
+        assert!(report.is_valid());
+        assert_eq!(2, report.records);
+
+        let fields = vec![TrailerField::Total("missing".to_string())];
+        let output = Validator::new(layout()).with_trailer(trailer.clone(), fields);
+        assert!(matches!(output, Err(PadderError::FieldCount { .. })));
+
+        let fields = vec![
+            TrailerField::Value(Value::from("99")),
+            TrailerField::RecordCount,
+            TrailerField::Total("missing".to_string()),
+        ];
+        let output = Validator::new(layout()).with_trailer(trailer, fields);
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn report_json() {
+        let report = Validator::new(layout()).validate(b"0a1ab    125Y\n");
+        let json: String = report.to_json().unwrap();
+        assert_eq!(
+            concat!(
+                r#"{"records":1,"invalid":1,"issues":[{"line":1,"column":2,"field":"id","#,
+                r#""kind":{"InvalidCharacter":97}}]}"#,
+            ),
+            json
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! The values that are written to, and read from, the fields of a record layout.
+//!
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::datetime::DateTime;
+use crate::PadderError;
+
+/// Exhaustive enum for the values of a field in a fixed-width record.
+///
+/// Text and bytes are borrowed whenever possible, so that values read from a record
+/// point directly into the record without allocating any memory.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// A missing value.
+    Null,
+    Text(Cow<'a, str>),
+    Bytes(Cow<'a, [u8]>),
+    Integer(i64),
+    /// A decimal number represented as an unscaled integer and its scale, that is, the
+    /// number of implied decimals, such that `Decimal(12345, 2)` is `123.45`.
+    Decimal(i64, u32),
+    Float(f64),
+    Boolean(bool),
+    DateTime(DateTime),
+}
+
+/// Get ten to the power of the exponent, failing if it does not fit an i64.
+fn pow10(exponent: u32) -> Result<i64, PadderError> {
+    10i64.checked_pow(exponent).ok_or(PadderError::OutOfRange)
+}
+
+/// Parse a decimal number like `-123.45` into its unscaled integer and scale.
+pub(crate) fn parse_decimal(text: &str) -> Result<(i64, u32), PadderError> {
+    let invalid = || PadderError::InvalidValue(format!("'{}' is not a number", text));
+
+    let (negative, unsigned) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut unscaled: i64 = 0;
+    for byte in integer.bytes().chain(fraction.bytes()) {
+        if !byte.is_ascii_digit() {
+            return Err(invalid());
+        }
+        unscaled = unscaled
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((byte - b'0') as i64))
+            .ok_or(PadderError::OutOfRange)?;
+    }
+
+    let unscaled: i64 = if negative { -unscaled } else { unscaled };
+    Ok((unscaled, fraction.len() as u32))
+}
+
+/// Change the scale of an unscaled integer, failing if any decimals would be lost.
+pub(crate) fn rescale(unscaled: i64, from: u32, to: u32) -> Result<i64, PadderError> {
+    if from <= to {
+        unscaled
+            .checked_mul(pow10(to - from)?)
+            .ok_or(PadderError::OutOfRange)
+    } else {
+        let divisor: i64 = pow10(from - to)?;
+        if unscaled % divisor != 0 {
+            return Err(PadderError::InvalidValue(format!(
+                "{} has more than {} decimals",
+                Value::Decimal(unscaled, from),
+                to,
+            )));
+        }
+        Ok(unscaled / divisor)
+    }
+}
+
+impl Value<'_> {
+    /// Check whether the value is missing.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Convert the value into an owned value that does not borrow any data.
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Self::Null => Value::Null,
+            Self::Text(text) => Value::Text(Cow::Owned(text.into_owned())),
+            Self::Bytes(bytes) => Value::Bytes(Cow::Owned(bytes.into_owned())),
+            Self::Integer(value) => Value::Integer(value),
+            Self::Decimal(value, scale) => Value::Decimal(value, scale),
+            Self::Float(value) => Value::Float(value),
+            Self::Boolean(value) => Value::Boolean(value),
+            Self::DateTime(value) => Value::DateTime(value),
+        }
+    }
+
+    /// Get the value as an unscaled integer with the given number of implied decimals,
+    /// or [`None`] if the value is missing or empty text.
+    ///
+    /// Text is parsed as a decimal number, and floats are rounded to the nearest integer
+    /// after scaling. Any other conversion that would lose decimals is an error.
+    pub fn to_scaled(&self, scale: u32) -> Result<Option<i64>, PadderError> {
+        match self {
+            Self::Null => Ok(None),
+            Self::Integer(value) => rescale(*value, 0, scale).map(Some),
+            Self::Decimal(value, from) => rescale(*value, *from, scale).map(Some),
+            Self::Float(value) => {
+                let scaled: f64 = (value * 10f64.powi(scale as i32)).round();
+                if !scaled.is_finite() || scaled < i64::MIN as f64 || scaled > i64::MAX as f64 {
+                    return Err(PadderError::OutOfRange);
+                }
+                Ok(Some(scaled as i64))
+            }
+            Self::Text(text) => {
+                let text: &str = text.trim();
+                if text.is_empty() {
+                    return Ok(None);
+                }
+                let (value, from) = parse_decimal(text)?;
+                rescale(value, from, scale).map(Some)
+            }
+            Self::Bytes(bytes) => match std::str::from_utf8(bytes) {
+                Ok(text) => Value::Text(Cow::Borrowed(text)).to_scaled(scale),
+                Err(_) => Err(PadderError::InvalidValue(
+                    "bytes are not a number".to_string(),
+                )),
+            },
+            Self::Boolean(_) | Self::DateTime(_) => Err(PadderError::InvalidValue(format!(
+                "{} is not a number",
+                self
+            ))),
+        }
+    }
+}
+
+/// Format the value as text, where a missing value is formatted as an empty string.
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => Ok(()),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Bytes(bytes) => write!(f, "{}", String::from_utf8_lossy(bytes)),
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Decimal(value, 0) => write!(f, "{}", value),
+            Self::Decimal(value, scale) => {
+                // Split the digits rather than divide, since large scales overflow a u64.
+                let scale: usize = *scale as usize;
+                let digits: String = format!("{:0width$}", value.unsigned_abs(), width = scale + 1);
+                let (integer, fraction) = digits.split_at(digits.len() - scale);
+                let sign: &str = if *value < 0 { "-" } else { "" };
+                write!(f, "{}{}.{}", sign, integer, fraction)
+            }
+            Self::Float(value) => write!(f, "{}", value),
+            Self::Boolean(value) => write!(f, "{}", value),
+            Self::DateTime(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Text(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(value: String) -> Self {
+        Self::Text(Cow::Owned(value))
+    }
+}
+
+impl<'a> From<&'a [u8]> for Value<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        Self::Bytes(Cow::Borrowed(value))
+    }
+}
+
+impl From<i64> for Value<'_> {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for Value<'_> {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<DateTime> for Value<'_> {
+    fn from(value: DateTime) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl<'a, T: Into<Value<'a>>> From<Option<T>> for Value<'a> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_decimal_text() {
+        assert_eq!(Ok((12345, 2)), parse_decimal("123.45"));
+        assert_eq!(Ok((-5, 1)), parse_decimal("-.5"));
+        assert_eq!(Ok((7, 0)), parse_decimal("+7"));
+        assert!(parse_decimal("1.2.3").is_err());
+        assert!(parse_decimal("-").is_err());
+    }
+
+    #[test]
+    fn to_scaled_conversions() {
+        assert_eq!(Ok(Some(1200)), Value::Integer(12).to_scaled(2));
+        assert_eq!(Ok(Some(1230)), Value::Decimal(123, 1).to_scaled(2));
+        assert_eq!(Ok(Some(1235)), Value::Float(12.345).to_scaled(2));
+        assert_eq!(Ok(Some(-1050)), Value::from(" -10.5 ").to_scaled(2));
+        assert_eq!(Ok(None), Value::from("  ").to_scaled(2));
+        assert_eq!(Ok(None), Value::Null.to_scaled(0));
+    }
+
+    #[test]
+    fn to_scaled_loses_decimals() {
+        let output = Value::Decimal(12345, 3).to_scaled(2);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+        assert_eq!(Ok(Some(1234)), Value::Decimal(12340, 3).to_scaled(2));
+    }
+
+    #[test]
+    fn to_scaled_not_a_number() {
+        let output = Value::Boolean(true).to_scaled(0);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+        let output = Value::from("12a").to_scaled(0);
+        assert!(matches!(output, Err(PadderError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn display_values() {
+        assert_eq!("", Value::Null.to_string());
+        assert_eq!("-0.05", Value::Decimal(-5, 2).to_string());
+        assert_eq!("123.450", Value::Decimal(123450, 3).to_string());
+        assert_eq!("42", Value::Decimal(42, 0).to_string());
+        assert_eq!(
+            "-0.0000000000000000000000005",
+            Value::Decimal(-5, 25).to_string()
+        );
+        assert_eq!("true", Value::Boolean(true).to_string());
+        assert_eq!(
+            "2024-01-31",
+            Value::from(DateTime::date(2024, 1, 31)).to_string()
+        );
+    }
+
+    #[test]
+    fn into_owned_value() {
+        let text = String::from("abc");
+        let value: Value<'static> = Value::from(text.as_str()).into_owned();
+        drop(text);
+        assert_eq!(Value::Text(Cow::Owned("abc".to_string())), value);
+    }
+
+    #[test]
+    fn from_option() {
+        assert_eq!(Value::Null, Value::from(None::<i64>));
+        assert_eq!(Value::Integer(3), Value::from(Some(3i64)));
+    }
+}
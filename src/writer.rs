@@ -0,0 +1,502 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Streaming fixed-width file writer.
+//!
+//! A [`FixedWidthWriter`] pads rows into records with a [`Layout`] and writes them to any
+//! [`io::Write`], each followed by the configured [`Terminator`]. An optional header
+//! record is written before the first row, and an optional trailer record is written
+//! when the writer is finished. The trailer can hold the number of records written and
+//! control totals of numeric fields, which are computed from exactly the values of the
+//! records that were written.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let layout = Layout::new(vec![
+//!     Field::new("type", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("amount", 6, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//! let trailer = Layout::new(vec![
+//!     Field::new("type", 2, Alignment::Left, Symbol::Whitespace, FieldKind::Text),
+//!     Field::new("count", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+//!     Field::new("total", 8, Alignment::Right, Symbol::Zero, FieldKind::Numeric).with_scale(2),
+//! ]);
+//!
+//! let mut writer = FixedWidthWriter::new(Vec::new(), layout)
+//!     .with_trailer(
+//!         trailer,
+//!         vec![
+//!             TrailerField::Value(Value::from("99")),
+//!             TrailerField::RecordCount,
+//!             TrailerField::Total("amount".to_string()),
+//!         ],
+//!     )
+//!     .unwrap();
+//!
+//! writer.write_row(&[Value::from("01"), Value::from("12.50")]).unwrap();
+//! writer.write_row(&[Value::from("01"), Value::from(3)]).unwrap();
+//!
+//! let output: Vec<u8> = writer.finish().unwrap();
+//! assert_eq!(b"01001250\n01000300\n9900200001550\n", output.as_slice());
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io;
+
+use crate::layout::Layout;
+use crate::value::Value;
+use crate::PadderError;
+
+/// Exhaustive enum for the terminators written after each record.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Terminator {
+    #[default]
+    Lf,
+    #[cfg_attr(feature = "cli", value(name = "crlf"))]
+    CrLf,
+    /// No terminator, for block formats where records are only separated by their width.
+    None,
+}
+
+impl Terminator {
+    /// Get the bytes of the terminator.
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::Lf => b"\n",
+            Self::CrLf => b"\r\n",
+            Self::None => b"",
+        }
+    }
+}
+
+/// Exhaustive enum for the values of the fields in a trailer record.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrailerField {
+    /// A constant value, like a record type code.
+    Value(Value<'static>),
+    /// The number of records written, excluding the header and trailer.
+    RecordCount,
+    /// The sum of the field with the given name over all records written, with the
+    /// scale of that field.
+    Total(String),
+}
+
+/// A trailer layout together with the values of its fields.
+struct Trailer {
+    layout: Layout,
+    fields: Vec<TrailerField>,
+}
+
+/// A streaming writer of fixed-width records, with an optional header and trailer.
+pub struct FixedWidthWriter<W: io::Write> {
+    writer: W,
+    layout: Layout,
+    terminator: Terminator,
+    header: Option<(Layout, Vec<Value<'static>>)>,
+    trailer: Option<Trailer>,
+    /// The index of each totaled field in the layout, and its running total.
+    totals: Vec<(usize, i64)>,
+    count: u64,
+    buffer: Vec<u8>,
+}
+
+impl<W: io::Write> FixedWidthWriter<W> {
+    /// Create a new [`FixedWidthWriter`] that writes records with the layout, each followed
+    /// by a line feed.
+    pub fn new(writer: W, layout: Layout) -> Self {
+        let buffer: Vec<u8> = Vec::with_capacity(layout.width() + 2);
+        Self {
+            writer,
+            layout,
+            terminator: Terminator::default(),
+            header: None,
+            trailer: None,
+            totals: Vec::new(),
+            count: 0,
+            buffer,
+        }
+    }
+
+    /// Set the terminator written after each record, including the header and trailer.
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Set a header record that is written with its own layout before the first row, or
+    /// when the writer is finished if no rows were written.
+    pub fn with_header(mut self, layout: Layout, values: Vec<Value<'static>>) -> Self {
+        self.header = Some((layout, values));
+        self
+    }
+
+    /// Set a trailer record that is written with its own layout when the writer is
+    /// finished, with one value per field of the trailer layout.
+    ///
+    /// Returns an error if the number of values does not match the trailer layout, or if
+    /// a total refers to a field that does not exist in the record layout.
+    pub fn with_trailer(
+        mut self,
+        layout: Layout,
+        fields: Vec<TrailerField>,
+    ) -> Result<Self, PadderError> {
+        if fields.len() != layout.fields().len() {
+            return Err(PadderError::FieldCount {
+                expected: layout.fields().len(),
+                found: fields.len(),
+            });
+        }
+
+        for field in &fields {
+            if let TrailerField::Total(name) = field {
+                let index: usize = self.layout.position(name).ok_or_else(|| {
+                    PadderError::InvalidLayout(format!(
+                        "total of '{}', which is not a field of the layout",
+                        name
+                    ))
+                })?;
+                if !self.totals.iter().any(|(i, _)| *i == index) {
+                    self.totals.push((index, 0));
+                }
+            }
+        }
+
+        self.trailer = Some(Trailer { layout, fields });
+        Ok(self)
+    }
+
+    /// Get the layout of the records.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Get the number of records written, excluding the header and trailer.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Get the running total of the field with the given name, if it is totaled by the
+    /// trailer.
+    pub fn total(&self, name: &str) -> Option<Value<'static>> {
+        let index: usize = self.layout.position(name)?;
+        let (_, total) = self.totals.iter().find(|(i, _)| *i == index)?;
+        Some(Value::Decimal(*total, self.layout.fields()[index].scale))
+    }
+
+    /// Write the buffered record followed by the terminator.
+    fn flush_record(&mut self) -> Result<(), PadderError> {
+        self.buffer.extend_from_slice(self.terminator.as_bytes());
+        let output: io::Result<()> = self.writer.write_all(&self.buffer);
+        self.buffer.clear();
+        output.map_err(PadderError::from)
+    }
+
+    /// Write the header record if there is one that has not been written yet.
+    fn write_header(&mut self) -> Result<(), PadderError> {
+        // The header is only cleared once written, so that it is retried after a failure.
+        if let Some((layout, values)) = &self.header {
+            if let Err(error) = layout.write(values, &mut self.buffer) {
+                self.buffer.clear();
+                return Err(error);
+            }
+            self.flush_record()?;
+            self.header = None;
+        }
+        Ok(())
+    }
+
+    /// Pad one value per field into a record and write it.
+    ///
+    /// If any value fails to be written, an error is returned, nothing is written, and
+    /// the record is neither counted nor added to the totals.
+    pub fn write_row(&mut self, values: &[Value]) -> Result<(), PadderError> {
+        self.write_header()?;
+
+        let mut totals: Vec<(usize, i64)> = self.totals.clone();
+        for (index, total) in totals.iter_mut() {
+            let field = &self.layout.fields()[*index];
+            let value: i64 = values
+                .get(*index)
+                .map(|v| v.to_scaled(field.scale))
+                .transpose()
+                .map_err(|error| PadderError::Field {
+                    name: field.name.clone(),
+                    error: Box::new(error),
+                })?
+                .flatten()
+                .unwrap_or(0);
+            *total = total.checked_add(value).ok_or(PadderError::OutOfRange)?;
+        }
+
+        self.layout.write(values, &mut self.buffer)?;
+        self.flush_record()?;
+        self.totals = totals;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Write the header if it has not been written yet and the trailer, flush the
+    /// underlying writer and return it.
+    pub fn finish(mut self) -> Result<W, PadderError> {
+        self.write_header()?;
+
+        if let Some(trailer) = self.trailer.take() {
+            let mut values: Vec<Value> = Vec::with_capacity(trailer.fields.len());
+            for field in &trailer.fields {
+                values.push(match field {
+                    TrailerField::Value(value) => value.clone(),
+                    TrailerField::RecordCount => Value::Integer(
+                        i64::try_from(self.count).map_err(|_| PadderError::OutOfRange)?,
+                    ),
+                    TrailerField::Total(name) => self.total(name).unwrap_or(Value::Null),
+                });
+            }
+            trailer.layout.write(&values, &mut self.buffer)?;
+            self.flush_record()?;
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{Field, FieldKind};
+    use crate::{Alignment, Symbol};
+
+    fn layout() -> Layout {
+        Layout::new(vec![
+            Field::new("id", 3, Alignment::Right, Symbol::Zero, FieldKind::Numeric),
+            Field::new(
+                "amount",
+                6,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+        ])
+    }
+
+    fn trailer() -> (Layout, Vec<TrailerField>) {
+        let layout = Layout::new(vec![
+            Field::new(
+                "type",
+                1,
+                Alignment::Left,
+                Symbol::Whitespace,
+                FieldKind::Text,
+            ),
+            Field::new(
+                "count",
+                3,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            ),
+            Field::new(
+                "total",
+                7,
+                Alignment::Right,
+                Symbol::Zero,
+                FieldKind::Numeric,
+            )
+            .with_scale(2),
+        ]);
+        let fields = vec![
+            TrailerField::Value(Value::from("T")),
+            TrailerField::RecordCount,
+            TrailerField::Total("amount".to_string()),
+        ];
+        (layout, fields)
+    }
+
+    fn header() -> (Layout, Vec<Value<'static>>) {
+        let layout = Layout::new(vec![Field::new(
+            "title",
+            9,
+            Alignment::Left,
+            Symbol::Whitespace,
+            FieldKind::Text,
+        )]);
+        (layout, vec![Value::from("HEADER")])
+    }
+
+    #[test]
+    fn write_with_header_and_trailer() {
+        let (header, values) = header();
+        let (trailer, fields) = trailer();
+        let mut writer = FixedWidthWriter::new(Vec::new(), layout())
+            .with_terminator(Terminator::CrLf)
+            .with_header(header, values)
+            .with_trailer(trailer, fields)
+            .unwrap();
+
+        writer
+            .write_row(&[Value::from(1), Value::from("10.25")])
+            .unwrap();
+        writer
+            .write_row(&[Value::from(2), Value::Decimal(-5, 1)])
+            .unwrap_err();
+        writer
+            .write_row(&[Value::from(3), Value::Float(0.1)])
+            .unwrap();
+        assert_eq!(2, writer.count());
+        assert_eq!(Some(Value::Decimal(1035, 2)), writer.total("amount"));
+
+        let output: Vec<u8> = writer.finish().unwrap();
+        assert_eq!(
+            "HEADER   \r\n001001025\r\n003000010\r\nT0020001035\r\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn write_block_format() {
+        let mut writer =
+            FixedWidthWriter::new(Vec::new(), layout()).with_terminator(Terminator::None);
+        writer.write_row(&[Value::from(1), Value::from(2)]).unwrap();
+        writer.write_row(&[Value::from(2), Value::Null]).unwrap();
+        let output: Vec<u8> = writer.finish().unwrap();
+        assert_eq!(b"001000200002000000", output.as_slice());
+    }
+
+    #[test]
+    fn write_header_and_trailer_without_rows() {
+        let (header, values) = header();
+        let (trailer, fields) = trailer();
+        let writer = FixedWidthWriter::new(Vec::new(), layout())
+            .with_header(header, values)
+            .with_trailer(trailer, fields)
+            .unwrap();
+        let output: Vec<u8> = writer.finish().unwrap();
+        assert_eq!(b"HEADER   \nT0000000000\n", output.as_slice());
+    }
+
+    #[test]
+    fn write_invalid_trailer() {
+        let (trailer, _) = trailer();
+        let output = FixedWidthWriter::new(Vec::new(), layout()).with_trailer(
+            trailer.clone(),
+            vec![
+                TrailerField::Value(Value::from("T")),
+                TrailerField::RecordCount,
+                TrailerField::Total("missing".to_string()),
+            ],
+        );
+        assert!(matches!(output, Err(PadderError::InvalidLayout(_))));
+
+        let output = FixedWidthWriter::new(Vec::new(), layout())
+            .with_trailer(trailer, vec![TrailerField::RecordCount]);
+        assert!(matches!(output, Err(PadderError::FieldCount { .. })));
+    }
+
+    #[test]
+    fn write_trailer_overflow() {
+        let (trailer, fields) = trailer();
+        let mut writer = FixedWidthWriter::new(Vec::new(), layout())
+            .with_trailer(trailer, fields)
+            .unwrap();
+        for _ in 0..11 {
+            writer
+                .write_row(&[Value::from(1), Value::from("9999.99")])
+                .unwrap();
+        }
+        let output = writer.finish();
+        assert!(matches!(output, Err(PadderError::Field { ref name, .. }) if name == "total"));
+    }
+
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_io_error() {
+        let mut writer = FixedWidthWriter::new(FailingWriter, layout());
+        let output = writer.write_row(&[Value::from(1), Value::from(2)]);
+        assert!(matches!(
+            output,
+            Err(PadderError::Io {
+                kind: io::ErrorKind::BrokenPipe,
+                ..
+            })
+        ));
+        assert_eq!(0, writer.count());
+    }
+
+    /// A writer that fails its first write.
+    struct FlakyWriter {
+        failed: bool,
+        output: Vec<u8>,
+    }
+
+    impl io::Write for FlakyWriter {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            if !self.failed {
+                self.failed = true;
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+            }
+            self.output.write(bytes)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn retry_header_after_io_error() {
+        let (header, values) = header();
+        let flaky = FlakyWriter {
+            failed: false,
+            output: Vec::new(),
+        };
+        let mut writer = FixedWidthWriter::new(flaky, layout()).with_header(header, values);
+        let row = [Value::from(1), Value::from(2)];
+        assert!(writer.write_row(&row).is_err());
+        writer.write_row(&row).unwrap();
+        let output: Vec<u8> = writer.finish().unwrap().output;
+        assert_eq!(b"HEADER   \n001000200\n", output.as_slice());
+    }
+}
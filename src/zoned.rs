@@ -0,0 +1,334 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//!
+//! Zoned decimal encoding and decoding, including COBOL signed overpunch.
+//!
+//! A zoned decimal field stores one digit per byte, right-aligned and padded with
+//! [`Symbol::Zero`] to the width of the field. A signed field carries its sign in the
+//! last digit, the so called overpunch, which replaces the digit with a character that
+//! encodes both the digit and the sign.
+//!
+//! ```
+//! use padder::*;
+//!
+//! let mut buffer: Vec<u8> = Vec::with_capacity(6);
+//! encode_overpunch(-1234, 6, Encoding::Ascii, &mut buffer).unwrap();
+//! assert_eq!(b"00123M", buffer.as_slice());
+//!
+//! let value: i64 = decode_overpunch(&buffer, Encoding::Ascii).unwrap();
+//! assert_eq!(-1234, value);
+//! ```
+//!
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{pad_and_push_to_buffer, Alignment, PadderError, Symbol};
+
+/// Exhaustive enum for the character encodings of zoned decimal fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Encoding {
+    /// Digits are the characters `0`-`9`, and the sign is overpunched as `{`, `A`-`I`
+    /// for positive values and `}`, `J`-`R` for negative values.
+    #[default]
+    Ascii,
+    /// Digits are the bytes `0xF0`-`0xF9`, and the sign is overpunched into the zone
+    /// nibble of the last digit as `0xC` for positive and `0xD` for negative values.
+    Ebcdic,
+}
+
+const ASCII_POSITIVE: &[u8; 10] = b"{ABCDEFGHI";
+const ASCII_NEGATIVE: &[u8; 10] = b"}JKLMNOPQR";
+
+const EBCDIC_POSITIVE: u8 = 0xC0;
+const EBCDIC_NEGATIVE: u8 = 0xD0;
+const EBCDIC_UNSIGNED: u8 = 0xF0;
+
+/// Write the decimal digits of the value to the end of the scratch array and return them.
+pub(crate) fn digits(value: u64, scratch: &mut [u8; 20]) -> &[u8] {
+    let mut value: u64 = value;
+    let mut start: usize = scratch.len();
+    loop {
+        start -= 1;
+        scratch[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    &scratch[start..]
+}
+
+/// Append the next digit to the accumulated value, failing if it no longer fits.
+pub(crate) fn accumulate(value: u64, digit: u8) -> Result<u64, PadderError> {
+    value
+        .checked_mul(10)
+        .and_then(|v| v.checked_add(digit as u64))
+        .ok_or(PadderError::OutOfRange)
+}
+
+/// Get the digit of an unsigned zoned byte in the given encoding.
+fn zoned_digit(byte: u8, encoding: Encoding) -> Option<u8> {
+    match encoding {
+        Encoding::Ascii => byte.is_ascii_digit().then(|| byte - b'0'),
+        Encoding::Ebcdic => {
+            (byte & 0xF0 == EBCDIC_UNSIGNED && byte & 0x0F <= 9).then_some(byte & 0x0F)
+        }
+    }
+}
+
+/// Get the digit and whether the value is negative from an overpunched byte.
+fn overpunch_digit(byte: u8, encoding: Encoding) -> Option<(u8, bool)> {
+    match encoding {
+        Encoding::Ascii => {
+            if let Some(digit) = ASCII_POSITIVE.iter().position(|&b| b == byte) {
+                Some((digit as u8, false))
+            } else if let Some(digit) = ASCII_NEGATIVE.iter().position(|&b| b == byte) {
+                Some((digit as u8, true))
+            } else {
+                zoned_digit(byte, encoding).map(|digit| (digit, false))
+            }
+        }
+        Encoding::Ebcdic => {
+            let digit: u8 = byte & 0x0F;
+            if digit > 9 {
+                return None;
+            }
+            match byte & 0xF0 {
+                EBCDIC_POSITIVE | EBCDIC_UNSIGNED => Some((digit, false)),
+                EBCDIC_NEGATIVE => Some((digit, true)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Encode an unsigned value as a zoned decimal field and push it to the buffer.
+///
+/// The digits are right-aligned and padded with [`Symbol::Zero`] to fit the target
+/// width. Unlike [`Source::pad`](crate::Source::pad) the value is never truncated,
+/// instead an error is returned if it has more digits than the width.
+pub fn encode_zoned(
+    value: u64,
+    width: usize,
+    encoding: Encoding,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    let mut scratch = [0u8; 20];
+    let digits: &[u8] = digits(value, &mut scratch);
+    if digits.len() > width {
+        return Err(PadderError::Overflow {
+            width,
+            required: digits.len(),
+        });
+    }
+
+    let start: usize = buffer.len();
+    pad_and_push_to_buffer(digits, width, Alignment::Right, Symbol::Zero, buffer);
+    if encoding == Encoding::Ebcdic {
+        buffer[start..]
+            .iter_mut()
+            .for_each(|b| *b = EBCDIC_UNSIGNED | (*b - b'0'));
+    }
+
+    Ok(())
+}
+
+/// Decode an unsigned zoned decimal field.
+pub fn decode_zoned(field: &[u8], encoding: Encoding) -> Result<u64, PadderError> {
+    if field.is_empty() {
+        return Err(PadderError::EmptyField);
+    }
+
+    field
+        .iter()
+        .enumerate()
+        .try_fold(0u64, |value, (position, &byte)| {
+            let digit: u8 =
+                zoned_digit(byte, encoding).ok_or(PadderError::InvalidDigit { position, byte })?;
+            accumulate(value, digit)
+        })
+}
+
+/// Encode a signed value as a zoned decimal field with an overpunched sign and push it
+/// to the buffer.
+pub fn encode_overpunch(
+    value: i64,
+    width: usize,
+    encoding: Encoding,
+    buffer: &mut Vec<u8>,
+) -> Result<(), PadderError> {
+    encode_zoned(value.unsigned_abs(), width, encoding, buffer)?;
+
+    // The width is at least one here, since any value has at least one digit.
+    let last: &mut u8 = buffer
+        .last_mut()
+        .expect("zoned field has at least one digit");
+    let digit: usize = (*last & 0x0F) as usize;
+    *last = match (encoding, value < 0) {
+        (Encoding::Ascii, false) => ASCII_POSITIVE[digit],
+        (Encoding::Ascii, true) => ASCII_NEGATIVE[digit],
+        (Encoding::Ebcdic, false) => EBCDIC_POSITIVE | digit as u8,
+        (Encoding::Ebcdic, true) => EBCDIC_NEGATIVE | digit as u8,
+    };
+
+    Ok(())
+}
+
+/// Decode a zoned decimal field with an overpunched sign.
+///
+/// A last digit without an overpunch is decoded as a positive value.
+pub fn decode_overpunch(field: &[u8], encoding: Encoding) -> Result<i64, PadderError> {
+    let (&last, leading) = field.split_last().ok_or(PadderError::EmptyField)?;
+    let (digit, negative) =
+        overpunch_digit(last, encoding).ok_or(PadderError::InvalidSign(last))?;
+
+    let magnitude: u64 = if leading.is_empty() {
+        digit as u64
+    } else {
+        accumulate(decode_zoned(leading, encoding)?, digit)?
+    };
+
+    let value: i128 = if negative {
+        -(magnitude as i128)
+    } else {
+        magnitude as i128
+    };
+
+    i64::try_from(value).map_err(|_| PadderError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_zoned_ascii() {
+        let mut buffer: Vec<u8> = Vec::with_capacity(6);
+        encode_zoned(1234, 6, Encoding::Ascii, &mut buffer).unwrap();
+        assert_eq!(b"001234", buffer.as_slice());
+    }
+
+    #[test]
+    fn encode_zoned_ebcdic() {
+        let mut buffer: Vec<u8> = Vec::with_capacity(4);
+        encode_zoned(709, 4, Encoding::Ebcdic, &mut buffer).unwrap();
+        assert_eq!(vec![0xF0, 0xF7, 0xF0, 0xF9], buffer);
+    }
+
+    #[test]
+    fn encode_zoned_overflow() {
+        let mut buffer: Vec<u8> = Vec::new();
+        let output = encode_zoned(12345, 4, Encoding::Ascii, &mut buffer);
+        let expected = Err(PadderError::Overflow {
+            width: 4,
+            required: 5,
+        });
+        assert_eq!(expected, output);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn decode_zoned_invalid_digit() {
+        let output = decode_zoned(b"12a4", Encoding::Ascii);
+        let expected = Err(PadderError::InvalidDigit {
+            position: 2,
+            byte: b'a',
+        });
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn decode_zoned_out_of_range() {
+        let output = decode_zoned(b"99999999999999999999", Encoding::Ascii);
+        assert_eq!(Err(PadderError::OutOfRange), output);
+    }
+
+    #[test]
+    fn encode_overpunch_ascii_positive() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_overpunch(123, 5, Encoding::Ascii, &mut buffer).unwrap();
+        encode_overpunch(0, 3, Encoding::Ascii, &mut buffer).unwrap();
+        assert_eq!(b"0012C00{", buffer.as_slice());
+    }
+
+    #[test]
+    fn encode_overpunch_ascii_negative() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_overpunch(-123, 5, Encoding::Ascii, &mut buffer).unwrap();
+        encode_overpunch(-10, 3, Encoding::Ascii, &mut buffer).unwrap();
+        assert_eq!(b"0012L01}", buffer.as_slice());
+    }
+
+    #[test]
+    fn encode_overpunch_ebcdic() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_overpunch(42, 3, Encoding::Ebcdic, &mut buffer).unwrap();
+        encode_overpunch(-42, 3, Encoding::Ebcdic, &mut buffer).unwrap();
+        assert_eq!(vec![0xF0, 0xF4, 0xC2, 0xF0, 0xF4, 0xD2], buffer);
+    }
+
+    #[test]
+    fn decode_overpunch_unsigned_last_digit() {
+        assert_eq!(Ok(123), decode_overpunch(b"00123", Encoding::Ascii));
+        assert_eq!(Ok(5), decode_overpunch(&[0xF5], Encoding::Ebcdic));
+    }
+
+    #[test]
+    fn decode_overpunch_invalid_sign() {
+        let output = decode_overpunch(b"001S", Encoding::Ascii);
+        assert_eq!(Err(PadderError::InvalidSign(b'S')), output);
+    }
+
+    #[test]
+    fn decode_overpunch_empty() {
+        let output = decode_overpunch(&[], Encoding::Ebcdic);
+        assert_eq!(Err(PadderError::EmptyField), output);
+    }
+
+    #[test]
+    fn overpunch_round_trip() {
+        let values: [i64; 8] = [0, 1, -1, 9, -90, 123456789, -987654321, i64::MIN + 1];
+        for encoding in [Encoding::Ascii, Encoding::Ebcdic] {
+            for value in values {
+                let mut buffer: Vec<u8> = Vec::new();
+                encode_overpunch(value, 19, encoding, &mut buffer).unwrap();
+                assert_eq!(19, buffer.len());
+                assert_eq!(Ok(value), decode_overpunch(&buffer, encoding));
+            }
+        }
+    }
+
+    #[test]
+    fn overpunch_round_trip_min() {
+        let mut buffer: Vec<u8> = Vec::new();
+        encode_overpunch(i64::MIN, 19, Encoding::Ascii, &mut buffer).unwrap();
+        assert_eq!(Ok(i64::MIN), decode_overpunch(&buffer, Encoding::Ascii));
+    }
+}
@@ -0,0 +1,221 @@
+//
+// MIT License
+//
+// Copyright (c) 2024 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// Run the binary with the arguments and the input on standard input.
+fn padder(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_padder"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+/// Get a path in the temporary directory that is unique to this process.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{}_{}", std::process::id(), name))
+}
+
+/// Write a layout spec with a numeric id and a text name to a temporary file.
+fn spec(name: &str) -> PathBuf {
+    let path = temp_path(name);
+    std::fs::write(
+        &path,
+        r#"{ "fields": [
+            { "name": "id", "width": 3, "symbol": "Zero", "kind": "Numeric" },
+            { "name": "name", "width": 5, "alignment": "Left" }
+        ] }"#,
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn pad_text() {
+    let output = padder(
+        &[
+            "pad", "--width", "6", "--align", "right", "--symbol", "zero", "42", "7",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!("000042\n000007\n", stdout(&output));
+
+    let output = padder(
+        &[
+            "pad", "--width", "4", "--align", "left", "--symbol", "space",
+        ],
+        "a\r\nbb\n",
+    );
+    assert!(output.status.success());
+    assert_eq!("a   \nbb  \n", stdout(&output));
+
+    let output = padder(&["pad", "--width", "4", "--symbol", "unknown", "a"], "");
+    assert!(!output.status.success());
+
+    let output = padder(&["pad", "--width", "2", "--align", "left", "åäö"], "");
+    assert!(output.status.success());
+    assert_eq!("åä\n", stdout(&output));
+    let output = padder(&["pad", "--width", "4", "--align", "center", "åä"], "");
+    assert_eq!(" åä \n", stdout(&output));
+}
+
+#[test]
+fn convert_both_ways() {
+    let spec = spec("padder_cli_convert.json");
+    let layout: &str = spec.to_str().unwrap();
+
+    let output = padder(
+        &[
+            "convert",
+            "csv2fw",
+            "--layout",
+            layout,
+            "--terminator",
+            "crlf",
+        ],
+        "name,id\n\"x, y\",7\nz,12\n",
+    );
+    assert!(output.status.success());
+    assert_eq!("007x, y \r\n012z    \r\n", stdout(&output));
+
+    let records: &str = "007x, y \r\n012z    \r\n";
+    let output = padder(&["convert", "fw2csv", "--layout", layout], records);
+    assert_eq!("id,name\n7,\"x, y\"\n12,z\n", stdout(&output));
+    let output = padder(
+        &[
+            "convert",
+            "fw2csv",
+            "--layout",
+            layout,
+            "--format",
+            "tsv",
+            "--no-headers",
+        ],
+        records,
+    );
+    assert_eq!("7\tx, y\n12\tz\n", stdout(&output));
+    let output = padder(
+        &["convert", "fw2csv", "--layout", layout, "--format", "jsonl"],
+        records,
+    );
+    std::fs::remove_file(&spec).unwrap();
+    assert_eq!(
+        "{\"id\":7,\"name\":\"x, y\"}\n{\"id\":12,\"name\":\"z\"}\n",
+        stdout(&output)
+    );
+}
+
+#[test]
+fn validate_records() {
+    let spec = spec("padder_cli_validate.json");
+    let layout: &str = spec.to_str().unwrap();
+
+    let output = padder(&["validate", "--layout", layout], "007a    \n012b    \n");
+    assert!(output.status.success());
+    assert_eq!("2 valid, 0 invalid records\n", stdout(&output));
+
+    let output = padder(
+        &["validate", "--layout", layout],
+        "007a\n0x2abcde\n012b    \n",
+    );
+    assert!(!output.status.success());
+    assert_eq!("1 valid, 2 invalid records\n", stdout(&output));
+    let errors = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        concat!(
+            "line 1, column 5: record length is 4 but the layout width is 8\n",
+            "line 2, column 2, field 'id': invalid character 'x'\n",
+        ),
+        errors
+    );
+
+    let output = padder(&["validate", "--layout", layout, "--json"], "0x2abcde\n");
+    assert!(!output.status.success());
+    assert!(stdout(&output).starts_with("{\"records\":1,\"invalid\":1,\"issues\":[{"));
+
+    std::fs::remove_file(&spec).unwrap();
+
+    let output = padder(&["validate", "--layout", "missing.json"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("padder: "));
+}
+
+#[test]
+fn layout_specs() {
+    for (name, spec) in [
+        (
+            "padder_cli_spec.toml",
+            "[[fields]]\nname = \"id\"\nwidth = 3\nsymbol = \"Zero\"\nkind = \"Numeric\"\n",
+        ),
+        (
+            "padder_cli_spec.yaml",
+            "fields:\n  - name: id\n    width: 3\n    symbol: Zero\n    kind: Numeric\n",
+        ),
+    ] {
+        let path = temp_path(name);
+        std::fs::write(&path, spec).unwrap();
+        let layout: &str = path.to_str().unwrap();
+        let output = padder(&["convert", "csv2fw", "--layout", layout], "id\n7\n");
+        std::fs::remove_file(&path).unwrap();
+        assert!(output.status.success());
+        assert_eq!("007\n", stdout(&output));
+    }
+}
+
+#[test]
+fn infer_layout() {
+    let output = padder(&["infer", "--lines", "2"], "007a   \n012bcd \nxyz\n");
+    assert!(output.status.success());
+
+    let path = temp_path("infer.json");
+    std::fs::write(&path, &output.stdout).unwrap();
+    let layout: &str = path.to_str().unwrap();
+    let output = padder(&["validate", "--layout", layout], "042xy  \n");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!("1 valid, 0 invalid records\n", stdout(&output));
+}